@@ -0,0 +1,56 @@
+use ar_reshaper::{
+    config::LigaturesFlags, ArabicReshaper, Language, ReshapeBuffer, ReshaperConfig,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const PARAGRAPH: &str = "اللغة العربية هي أكثر اللغات تحدثاً ونطقاً ضمن مجموعة اللغات السامية، \
+    وإحدى أكثر اللغات انتشاراً في العالم، يتحدثها أكثر من 467 مليون نسمة. \
+    زبان فارسی یکی از زبان‌های هندواروپایی در شاخهٔ زبان‌های ایرانی جنوب غربی است \
+    که در کشورهای ایران، افغانستان، تاجیکستان و ازبکستان به آن سخن می‌گویند.";
+
+fn paragraph() -> String {
+    PARAGRAPH.repeat(16)
+}
+
+fn reshape(c: &mut Criterion) {
+    let text = paragraph();
+
+    let reshaper = ArabicReshaper::default();
+    c.bench_function("reshape default", |b| b.iter(|| reshaper.reshape(&text)));
+
+    let mut buffer = ReshapeBuffer::new();
+    c.bench_function("reshape default with buffer", |b| {
+        b.iter(|| buffer.reshape(&reshaper, &text).len())
+    });
+
+    let reshaper = ArabicReshaper::new(ReshaperConfig::new(
+        Language::Arabic,
+        LigaturesFlags::none(),
+    ));
+    c.bench_function("reshape without ligatures", |b| {
+        b.iter(|| reshaper.reshape(&text))
+    });
+
+    let reshaper =
+        ArabicReshaper::new(ReshaperConfig::new(Language::Arabic, LigaturesFlags::all()));
+    c.bench_function("reshape all ligatures", |b| {
+        b.iter(|| reshaper.reshape(&text))
+    });
+}
+
+fn need_reshape(c: &mut Criterion) {
+    let reshaper = ArabicReshaper::default();
+
+    let text = paragraph();
+    c.bench_function("need_reshape arabic", |b| {
+        b.iter(|| reshaper.need_reshape(&text))
+    });
+
+    let text = "The quick brown fox jumps over the lazy dog. ".repeat(64);
+    c.bench_function("need_reshape latin", |b| {
+        b.iter(|| reshaper.need_reshape(&text))
+    });
+}
+
+criterion_group!(benches, reshape, need_reshape);
+criterion_main!(benches);
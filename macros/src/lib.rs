@@ -0,0 +1,227 @@
+//! # ar-reshaper-macros
+//!
+//! Reshape string literals at compile time with [ar-reshaper](https://docs.rs/ar-reshaper),
+//! the reshaped text is a `&'static str`, so the letters and ligatures of the reshaper are
+//! not needed in the binary.
+//! ```rust
+//! use ar_reshaper_macros::reshape;
+//!
+//! const GREETING: &str = reshape!("سلام دنیا");
+//! assert_eq!(GREETING, "ﺳﻼﻡ ﺩﻧﯿﺎ");
+//! ```
+//!
+//! The config can be given with `config = ...`, it's written like the code that makes a
+//! [`ReshaperConfig`] but only literal values can be used in it.
+//! ```rust
+//! use ar_reshaper_macros::reshape;
+//!
+//! let text = reshape!(
+//!     config = ReshaperConfig {
+//!         delete_harakat: false,
+//!         ..ReshaperConfig::new(Language::Urdu, LigaturesFlags::default())
+//!     },
+//!     "سلام"
+//! );
+//! assert_eq!(text, "ﺳﻼﻡ");
+//! ```
+//!
+//! The `visual_ordering` option needs the `bidi` feature of this crate.
+#![forbid(unsafe_code)]
+
+use ar_reshaper::{
+    config::{FormsFallback, LigaturesFlags},
+    ArabicReshaper, Language, ReshaperConfig,
+};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    Error, Expr, ExprCall, ExprPath, ExprStruct, Ident, Lit, LitStr, Member, Result, Token,
+};
+
+/// Reshape a string literal at compile time and return it as a `&'static str`.
+///
+/// See the [crate] documentation for the config.
+#[proc_macro]
+pub fn reshape(input: TokenStream) -> TokenStream {
+    let Input { config, text } = parse_macro_input!(input as Input);
+    let reshaped = LitStr::new(
+        &ArabicReshaper::new(config).reshape(text.value()),
+        text.span(),
+    );
+    quote!(#reshaped).into()
+}
+
+struct Input {
+    config: ReshaperConfig,
+    text: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut config = ReshaperConfig::default();
+
+        if input.peek(Ident) {
+            let name: Ident = input.parse()?;
+            if name != "config" {
+                return Err(Error::new(
+                    name.span(),
+                    "expected `config` or a string literal",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            config = parse_config(&input.parse()?)?;
+            input.parse::<Token![,]>()?;
+        }
+
+        let text = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { config, text })
+    }
+}
+
+/// The last part of the path, like `Urdu` in `Language::Urdu`
+fn last_name(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Path(ExprPath { path, .. }) => Ok(path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default()),
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn unsupported(expr: &impl Spanned) -> Error {
+    Error::new(
+        expr.span(),
+        "only literal values can be used in the config of `reshape!`",
+    )
+}
+
+/// Parse `ReshaperConfig::default()`, `ReshaperConfig::new(language, flags)` or
+/// `ReshaperConfig { field: value, ..base }`
+fn parse_config(expr: &Expr) -> Result<ReshaperConfig> {
+    match expr {
+        Expr::Call(ExprCall { func, args, .. }) => match (last_name(func)?.as_str(), args.len()) {
+            ("default", 0) => Ok(ReshaperConfig::default()),
+            ("new", 2) => Ok(ReshaperConfig::new(
+                parse_language(&args[0])?,
+                parse_ligatures_flags(&args[1])?,
+            )),
+            _ => Err(unsupported(expr)),
+        },
+        Expr::Struct(ExprStruct { fields, rest, .. }) => {
+            let mut config = match rest {
+                Some(rest) => parse_config(rest)?,
+                None => ReshaperConfig::default(),
+            };
+
+            for field in fields {
+                let Member::Named(name) = &field.member else {
+                    return Err(unsupported(&field.member));
+                };
+
+                match name.to_string().as_str() {
+                    "language" => config.language = parse_language(&field.expr)?,
+                    "delete_harakat" => config.delete_harakat = parse_bool(&field.expr)?,
+                    "shift_harakat_position" => {
+                        config.shift_harakat_position = parse_bool(&field.expr)?
+                    }
+                    "delete_tatweel" => config.delete_tatweel = parse_bool(&field.expr)?,
+                    "support_zwj" => config.support_zwj = parse_bool(&field.expr)?,
+                    "use_unshaped_instead_of_isolated" => {
+                        config.use_unshaped_instead_of_isolated = parse_bool(&field.expr)?
+                    }
+                    "support_ligatures" => config.support_ligatures = parse_bool(&field.expr)?,
+                    "forms_fallback" => config.forms_fallback = parse_forms_fallback(&field.expr)?,
+                    "visual_ordering" => set_visual_ordering(&mut config, &field.expr)?,
+                    _ => {
+                        return Err(Error::new(
+                            name.span(),
+                            format!("`{name}` can't be set in the config of `reshape!`"),
+                        ))
+                    }
+                }
+            }
+
+            Ok(config)
+        }
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn parse_bool(expr: &Expr) -> Result<bool> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Bool(value) => Ok(value.value),
+            _ => Err(Error::new(lit.span(), "expected `true` or `false`")),
+        },
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn parse_language(expr: &Expr) -> Result<Language> {
+    match last_name(expr)?.as_str() {
+        "Arabic" => Ok(Language::Arabic),
+        "ArabicV2" => Ok(Language::ArabicV2),
+        "Kurdish" => Ok(Language::Kurdish),
+        "Urdu" => Ok(Language::Urdu),
+        "Pashto" => Ok(Language::Pashto),
+        "Sindhi" => Ok(Language::Sindhi),
+        _ => Err(Error::new(
+            expr.span(),
+            "expected Arabic, ArabicV2, Kurdish, Urdu, Pashto or Sindhi, custom languages can't be used in `reshape!`",
+        )),
+    }
+}
+
+fn parse_ligatures_flags(expr: &Expr) -> Result<LigaturesFlags> {
+    let Expr::Call(ExprCall { func, args, .. }) = expr else {
+        return Err(unsupported(expr));
+    };
+
+    match (last_name(func)?.as_str(), args.is_empty()) {
+        ("default", true) => Ok(LigaturesFlags::default()),
+        ("all", true) => Ok(LigaturesFlags::all()),
+        ("none", true) => Ok(LigaturesFlags::none()),
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn parse_forms_fallback(expr: &Expr) -> Result<FormsFallback> {
+    match expr {
+        Expr::Call(ExprCall { func, args, .. }) if last_name(func)? == "PrivateUse" => {
+            match args.first() {
+                Some(Expr::Lit(lit)) if args.len() == 1 => match &lit.lit {
+                    Lit::Char(c) => Ok(FormsFallback::PrivateUse(c.value())),
+                    _ => Err(Error::new(lit.span(), "expected a char")),
+                },
+                _ => Err(unsupported(expr)),
+            }
+        }
+        _ => match last_name(expr)?.as_str() {
+            "Base" => Ok(FormsFallback::Base),
+            "Zwj" => Ok(FormsFallback::Zwj),
+            _ => Err(unsupported(expr)),
+        },
+    }
+}
+
+#[cfg(feature = "bidi")]
+fn set_visual_ordering(config: &mut ReshaperConfig, expr: &Expr) -> Result<()> {
+    config.visual_ordering = parse_bool(expr)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "bidi"))]
+fn set_visual_ordering(_: &mut ReshaperConfig, expr: &Expr) -> Result<()> {
+    Err(Error::new(
+        expr.span(),
+        "`visual_ordering` needs the `bidi` feature of ar-reshaper-macros",
+    ))
+}
@@ -0,0 +1,77 @@
+use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
+use ar_reshaper_macros::reshape;
+
+const GREETING: &str = reshape!("سلام دنیا");
+
+#[test]
+fn default_config() {
+    assert_eq!(GREETING, ArabicReshaper::default().reshape("سلام دنیا"));
+    assert_eq!(reshape!("hello",), "hello");
+}
+
+#[test]
+fn config() {
+    let text = "اللّه یَکّی، دو. ٣";
+
+    let expected = ArabicReshaper::new(ReshaperConfig {
+        delete_harakat: false,
+        ..ReshaperConfig::new(Language::Urdu, LigaturesFlags::all())
+    })
+    .reshape(text);
+    assert_eq!(
+        reshape!(
+            config = ReshaperConfig {
+                delete_harakat: false,
+                ..ReshaperConfig::new(Language::Urdu, LigaturesFlags::all())
+            },
+            "اللّه یَکّی، دو. ٣"
+        ),
+        expected
+    );
+
+    let expected = ArabicReshaper::new(ReshaperConfig {
+        language: Language::Kurdish,
+        support_zwj: false,
+        ..Default::default()
+    })
+    .reshape("ب\u{200D}");
+    assert_eq!(
+        reshape!(
+            config = ReshaperConfig {
+                language: Language::Kurdish,
+                support_zwj: false,
+                ..Default::default()
+            },
+            "ب\u{200D}"
+        ),
+        expected
+    );
+
+    assert_eq!(
+        reshape!(
+            config = ReshaperConfig::new(Language::Arabic, LigaturesFlags::none()),
+            "لا"
+        ),
+        "ﻟﺎ"
+    );
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn visual_ordering() {
+    let config = ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        reshape!(
+            config = ReshaperConfig {
+                visual_ordering: true,
+                ..Default::default()
+            },
+            "سلام 123 دنیا"
+        ),
+        ArabicReshaper::new(config).reshape("سلام 123 دنیا")
+    );
+}
@@ -0,0 +1,156 @@
+//! Visual ordering
+//!
+//! Run the Unicode Bidirectional Algorithm over reshaped text and return it in
+//! display order, so it can be drawn by renderers that only support left-to-right text.
+
+use alloc::{string::String, vec::Vec};
+
+use unicode_bidi::{BidiClass, BidiInfo, Level};
+
+use crate::reshaper::is_haraka;
+
+/// Pairs of characters that should be swapped when they appear inside a
+/// right-to-left run (a small subset of `BidiMirroring.txt`).
+static MIRRORED: [(char, char); 10] = [
+    ('(', ')'),
+    ('<', '>'),
+    ('[', ']'),
+    ('{', '}'),
+    ('«', '»'),
+    ('‹', '›'),
+    ('⁅', '⁆'),
+    ('⁽', '⁾'),
+    ('₍', '₎'),
+    ('≤', '≥'),
+];
+
+fn mirror(c: char) -> char {
+    MIRRORED
+        .iter()
+        .find_map(|&(a, b)| match c {
+            _ if c == a => Some(b),
+            _ if c == b => Some(a),
+            _ => None,
+        })
+        .unwrap_or(c)
+}
+
+/// Reorder the given text from logical order to visual (display) order.
+///
+/// Every paragraph is reordered separately and paragraph separators are kept in place.
+/// Left-to-right runs such as Latin words and numbers keep their order inside right-to-left
+/// lines, mirrored characters like brackets are swapped in right-to-left runs and
+/// Harakat stay after the letter they belong to.
+pub fn visual_order(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    reorder(text, None, |_, c| result.push(c));
+    result
+}
+
+/// Reorder the text that is returned by [`visual_order`] back to logical order.
+///
+/// The direction of a paragraph can't always be told from its visual order (`سلام hello`
+/// and `hello سلام` look the same), so every paragraph is read as a right-to-left paragraph
+/// first, and as a left-to-right paragraph when that doesn't give back the same visual order.
+pub(crate) fn logical_order(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut logical = String::new();
+
+    for para in text.split_inclusive(is_paragraph_separator) {
+        for level in [Level::rtl(), Level::ltr()] {
+            logical.clear();
+            reorder(para, Some(level), |_, c| logical.push(c));
+            if visual_order(&logical) == para {
+                break;
+            }
+        }
+        result.push_str(&logical);
+    }
+
+    result
+}
+
+/// Check if the character ends a paragraph for the Unicode Bidirectional Algorithm
+fn is_paragraph_separator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{1C}'..='\u{1E}' | '\u{85}' | '\u{2029}')
+}
+
+/// Same as [`visual_order`], the map has an item for each character of the
+/// text and is reordered the same way as the text.
+pub(crate) fn visual_order_map<T: Clone>(text: &str, map: &mut Vec<T>) -> String {
+    let starts: Vec<usize> = text.char_indices().map(|(idx, _)| idx).collect();
+    let mut result = String::with_capacity(text.len());
+    let mut reordered = Vec::with_capacity(map.len());
+
+    reorder(text, None, |idx, c| {
+        result.push(c);
+        if let Ok(pos) = starts.binary_search(&idx) {
+            reordered.extend(map.get(pos).cloned());
+        }
+    });
+
+    *map = reordered;
+    result
+}
+
+/// Call `push` with every character of the text in visual order and its position in the text,
+/// the direction of the paragraphs is detected from the text when `level` is [`None`].
+fn reorder<F>(text: &str, level: Option<Level>, mut push: F)
+where
+    F: FnMut(usize, char),
+{
+    let info = BidiInfo::new(text, level);
+
+    if !info.has_rtl() {
+        text.char_indices().for_each(|(idx, c)| push(idx, c));
+        return;
+    }
+
+    let push_str = |push: &mut F, start: usize, text: &str| {
+        text.char_indices()
+            .for_each(|(idx, c)| push(start + idx, c));
+    };
+
+    for para in &info.paragraphs {
+        // keep the paragraph separator at the end of the line
+        let mut line = para.range.clone();
+        while line.end > line.start && info.original_classes[line.end - 1] == BidiClass::B {
+            line.end -= 1;
+            while !text.is_char_boundary(line.end) {
+                line.end -= 1;
+            }
+        }
+
+        let (levels, runs) = info.visual_runs(para, line.clone());
+        let mut cluster = Vec::new();
+
+        for run in runs {
+            let run_text = &text[run.clone()];
+
+            if !levels[run.start].is_rtl() {
+                push_str(&mut push, run.start, run_text);
+                continue;
+            }
+
+            // Reverse the run cluster by cluster, so Harakat still follow their letter
+            let mut chars = run_text.char_indices().rev().peekable();
+            while let Some((idx, c)) = chars.next() {
+                cluster.push((idx, c));
+                if is_haraka(c) {
+                    while let Some(&(idx, next)) = chars.peek() {
+                        chars.next();
+                        cluster.push((idx, next));
+                        if !is_haraka(next) {
+                            break;
+                        }
+                    }
+                }
+                for (idx, c) in cluster.drain(..).rev() {
+                    push(run.start + idx, mirror(c));
+                }
+            }
+        }
+
+        push_str(&mut push, line.end, &text[line.end..para.range.end]);
+    }
+}
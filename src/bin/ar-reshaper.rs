@@ -0,0 +1,342 @@
+//! Command-line interface of `ar-reshaper`, reshape files or the standard input.
+
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use ar_reshaper::{
+    config::{FormsFallback, LigaturesFlags},
+    io::ReshapingWriter,
+    prelude::LigatureNames,
+    Language, ReshaperConfig,
+};
+
+const USAGE: &str = "\
+Reshape Arabic, Persian and Urdu text for renderers that don't support Arabic script
+
+Usage: ar-reshaper [OPTIONS] [FILES]...
+
+Reshape the given files, or the standard input when there is no file or the file
+is `-`, and write them to the standard output.
+
+Options:
+  -i, --in-place              Reshape the files in place instead of writing them to the output
+      --language <LANGUAGE>   arabic, arabic-v2, kurdish, urdu, pashto or sindhi [default: arabic]
+      --keep-harakat          Keep the harakat instead of deleting them
+      --shift-harakat         Put the harakat one letter before where they are
+      --delete-tatweel        Delete the tatweel
+      --no-zwj                Don't use the ZWJ to join the letters
+      --unshaped-isolated     Keep the isolated letters unshaped
+      --ligatures <LIGATURES> default, all, none or a comma separated list of ligature names
+      --forms-fallback <FALLBACK>
+                              How to write the letters without presentation forms:
+                              base, zwj or private-use:<first code point in hex>
+      --visual-order          Reorder the reshaped text to visual order
+      --config <FILE>         Read the config from a JSON file, the options are applied on top of it
+      --font <FILE>           Only use the forms and ligatures that the TrueType font has
+  -h, --help                  Print help
+  -V, --version               Print version
+
+Exit status is 0 on success, 1 if a file can't be reshaped and 2 if the options are not valid.";
+
+/// Exit code for the errors in the arguments
+const USAGE_ERROR: u8 = 2;
+
+/// Options that take a value
+const VALUE_OPTIONS: [&str; 5] = [
+    "--language",
+    "--ligatures",
+    "--forms-fallback",
+    "--config",
+    "--font",
+];
+
+#[derive(Clone)]
+enum LigaturesOption {
+    Flags(LigaturesFlags),
+    Names(Vec<LigatureNames>),
+}
+
+#[derive(Default)]
+struct Options {
+    in_place: bool,
+    language: Option<Language>,
+    keep_harakat: bool,
+    shift_harakat: bool,
+    delete_tatweel: bool,
+    no_zwj: bool,
+    unshaped_isolated: bool,
+    ligatures: Option<LigaturesOption>,
+    forms_fallback: Option<FormsFallback>,
+    visual_order: bool,
+    config: Option<PathBuf>,
+    font: Option<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+enum Command {
+    Help,
+    Version,
+    Reshape(Options),
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\nFor more information, try '--help'.");
+            return ExitCode::from(USAGE_ERROR);
+        }
+    };
+
+    let options = match command {
+        Command::Help => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Command::Version => {
+            println!("ar-reshaper {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Command::Reshape(options) => options,
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    let mut only_files = false;
+
+    while let Some(arg) = args.next() {
+        if only_files || arg == "-" || !arg.starts_with('-') {
+            options.files.push(PathBuf::from(arg));
+            continue;
+        }
+
+        // options can have their value after `=` or in the next argument
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        if inline_value.is_some() && !VALUE_OPTIONS.contains(&name) {
+            return Err(format!("'{name}' doesn't take a value"));
+        }
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("a value is required for '{name}'"))
+        };
+
+        match name {
+            "--" => only_files = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-i" | "--in-place" => options.in_place = true,
+            "--language" => options.language = Some(parse_language(&value()?)?),
+            "--keep-harakat" => options.keep_harakat = true,
+            "--shift-harakat" => options.shift_harakat = true,
+            "--delete-tatweel" => options.delete_tatweel = true,
+            "--no-zwj" => options.no_zwj = true,
+            "--unshaped-isolated" => options.unshaped_isolated = true,
+            "--ligatures" => options.ligatures = Some(parse_ligatures(&value()?)?),
+            "--forms-fallback" => options.forms_fallback = Some(parse_forms_fallback(&value()?)?),
+            "--visual-order" => options.visual_order = true,
+            "--config" => options.config = Some(PathBuf::from(value()?)),
+            "--font" => options.font = Some(PathBuf::from(value()?)),
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    if options.config.is_some() && options.font.is_some() {
+        return Err(String::from("'--config' can't be used with '--font'"));
+    }
+    if options.in_place && options.files.iter().any(|file| file.as_os_str() == "-") {
+        return Err(String::from(
+            "the standard input can't be reshaped in place",
+        ));
+    }
+    if options.in_place && options.files.is_empty() {
+        return Err(String::from("'--in-place' needs at least one file"));
+    }
+
+    Ok(Command::Reshape(options))
+}
+
+fn parse_language(value: &str) -> Result<Language, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "arabic" => Ok(Language::Arabic),
+        "arabic-v2" | "arabicv2" => Ok(Language::ArabicV2),
+        "kurdish" => Ok(Language::Kurdish),
+        "urdu" => Ok(Language::Urdu),
+        "pashto" => Ok(Language::Pashto),
+        "sindhi" => Ok(Language::Sindhi),
+        _ => Err(format!("unknown language '{value}'")),
+    }
+}
+
+fn parse_ligatures(value: &str) -> Result<LigaturesOption, String> {
+    match value {
+        "default" => Ok(LigaturesOption::Flags(LigaturesFlags::default())),
+        "all" => Ok(LigaturesOption::Flags(LigaturesFlags::all())),
+        "none" => Ok(LigaturesOption::Flags(LigaturesFlags::none())),
+        names => names
+            .split(',')
+            .map(|name| {
+                LigatureNames::from_name(name.trim())
+                    .ok_or_else(|| format!("unknown ligature '{}'", name.trim()))
+            })
+            .collect::<Result<_, _>>()
+            .map(LigaturesOption::Names),
+    }
+}
+
+fn parse_forms_fallback(value: &str) -> Result<FormsFallback, String> {
+    match value.split_once(':') {
+        None if value == "base" => Ok(FormsFallback::Base),
+        None if value == "zwj" => Ok(FormsFallback::Zwj),
+        Some(("private-use", start)) => u32::from_str_radix(start, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .map(FormsFallback::PrivateUse)
+            .ok_or_else(|| format!("invalid code point '{start}'")),
+        _ => Err(format!("unknown forms fallback '{value}'")),
+    }
+}
+
+/// Build the config from the config file or the font, and apply the options on top of it.
+fn build_config(options: &Options) -> Result<ReshaperConfig, String> {
+    let language = options.language.clone();
+
+    let mut config = match (&options.config, &options.font) {
+        (Some(path), _) => {
+            let text = fs::read_to_string(path).map_err(|e| file_error(path, e))?;
+            serde_json::from_str(&text)
+                .map_err(|e| format!("{}: invalid config: {e}", path.display()))?
+        }
+        (None, Some(path)) => {
+            let font = fs::read(path).map_err(|e| file_error(path, e))?;
+            // every ligature is checked, the `--ligatures` option is applied later
+            ReshaperConfig::from_font(
+                &font,
+                language.clone().unwrap_or_default(),
+                LigaturesFlags::all(),
+            )
+            .map_err(|e| format!("{}: invalid font: {e}", path.display()))?
+        }
+        (None, None) => ReshaperConfig::default(),
+    };
+
+    if let Some(language) = language {
+        config.language = language;
+    }
+
+    // without a config file the default ligatures depend on the language
+    let ligatures = options.ligatures.clone().or_else(|| {
+        (options.config.is_none() && options.font.is_none())
+            .then_some(LigaturesOption::Flags(LigaturesFlags::default()))
+    });
+
+    if let Some(ligatures) = ligatures {
+        let wanted = ReshaperConfig::new(
+            config.language.clone(),
+            match ligatures {
+                LigaturesOption::Flags(flags) => flags,
+                LigaturesOption::Names(_) => LigaturesFlags::none(),
+            },
+        );
+
+        for ligature in LigatureNames::ALL {
+            let enabled = match &ligatures {
+                LigaturesOption::Flags(_) => wanted.ligatures.is_ligature_enabled(ligature),
+                LigaturesOption::Names(names) => names.contains(&ligature),
+            };
+            // with a font only the ligatures that the font has can be enabled
+            let available =
+                options.font.is_none() || config.ligatures.is_ligature_enabled(ligature);
+            config.update_ligature(ligature, enabled && available);
+        }
+    }
+
+    if options.keep_harakat {
+        config.delete_harakat = false;
+    }
+    if options.shift_harakat {
+        config.shift_harakat_position = true;
+    }
+    if options.delete_tatweel {
+        config.delete_tatweel = true;
+    }
+    if options.no_zwj {
+        config.support_zwj = false;
+    }
+    if options.unshaped_isolated {
+        config.use_unshaped_instead_of_isolated = true;
+    }
+    if let Some(forms_fallback) = options.forms_fallback {
+        config.forms_fallback = forms_fallback;
+    }
+    if options.visual_order {
+        config.visual_ordering = true;
+    }
+
+    Ok(config)
+}
+
+fn file_error(path: &Path, error: io::Error) -> String {
+    format!("{}: {error}", path.display())
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let config = build_config(options)?;
+
+    if options.in_place {
+        for path in &options.files {
+            let text = fs::read(path).map_err(|e| file_error(path, e))?;
+
+            let mut writer = ReshapingWriter::with_config(config.clone(), Vec::new());
+            writer.write_all(&text).map_err(|e| file_error(path, e))?;
+            let reshaped = writer.finish().map_err(|e| file_error(path, e))?;
+
+            fs::write(path, reshaped).map_err(|e| file_error(path, e))?;
+        }
+        return Ok(());
+    }
+
+    let stdin = Path::new("-");
+    let files = match options.files.is_empty() {
+        true => vec![stdin.to_path_buf()],
+        false => options.files.clone(),
+    };
+
+    let mut stdout = io::stdout().lock();
+    for path in &files {
+        // every file has its own writer, so its last line doesn't join the next file
+        let mut writer = ReshapingWriter::with_config(config.clone(), &mut stdout);
+        let result = match path == stdin {
+            true => io::copy(&mut io::stdin().lock(), &mut writer),
+            false => fs::File::open(path).and_then(|mut file| io::copy(&mut file, &mut writer)),
+        };
+        result.map_err(|e| file_error(path, e))?;
+
+        if let Err(e) = writer.finish() {
+            return Err(format!("can't write the output: {e}"));
+        }
+    }
+
+    Ok(())
+}
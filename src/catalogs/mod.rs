@@ -0,0 +1,177 @@
+//! Localization catalogs
+//!
+//! Reshape the translated strings of gettext (`.po` and `.pot`), Android `strings.xml`
+//! and XLIFF 1.2 and 2.0 catalogs. The keys, source strings, comments and the structure of
+//! the file are written back as they are, and the placeholders inside the messages, like
+//! printf `%s` and `%1$d` or ICU `{name}`, are not changed by reshaping.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    markup::{brace_len, printf_len, Token, Token::*},
+    ArabicReshaper, ReshaperConfig,
+};
+
+mod po;
+mod xml;
+
+/// Supported catalog formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CatalogFormat {
+    /// gettext catalogs and templates (`.po` and `.pot`)
+    Po,
+    /// Android string resources (`strings.xml`)
+    AndroidStrings,
+    /// XLIFF 1.2 and 2.0 (`.xlf` and `.xliff`)
+    Xliff,
+}
+
+impl CatalogFormat {
+    /// Find the format from the extension of the file, like `po` or `xlf`
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "po" | "pot" => Some(Self::Po),
+            "xml" => Some(Self::AndroidStrings),
+            "xlf" | "xliff" => Some(Self::Xliff),
+            _ => None,
+        }
+    }
+
+    /// Find the format from the content of the file
+    pub fn detect(text: &str) -> Option<Self> {
+        // Android resources can have `<xliff:g>` elements in them
+        if text.contains("<resources") {
+            Some(Self::AndroidStrings)
+        } else if text.contains("<xliff") {
+            Some(Self::Xliff)
+        } else if text.lines().any(|line| line.starts_with("msgid ")) {
+            Some(Self::Po)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reshape the translated strings of the catalogs.
+/// ```rust
+/// use ar_reshaper::{
+///     catalogs::{CatalogFormat, CatalogProcessor},
+///     ReshaperConfig,
+/// };
+///
+/// let processor = CatalogProcessor::new(ReshaperConfig::default());
+/// let po = "msgid \"Hello %s\"\nmsgstr \"سلام %s\"\n";
+///
+/// assert_eq!(
+///     processor.reshape(po, CatalogFormat::Po),
+///     "msgid \"Hello %s\"\nmsgstr \"ﺳﻼﻡ %s\"\n"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CatalogProcessor {
+    reshaper: ArabicReshaper,
+}
+
+impl CatalogProcessor {
+    /// Create a new [CatalogProcessor] using the given config
+    pub fn new(config: ReshaperConfig) -> Self {
+        Self {
+            reshaper: ArabicReshaper::new(config),
+        }
+    }
+
+    /// Reshape the translated strings of the catalog and return the whole catalog
+    pub fn reshape(&self, text: &str, format: CatalogFormat) -> String {
+        match format {
+            CatalogFormat::Po => po::reshape(&self.reshaper, text),
+            CatalogFormat::AndroidStrings => xml::reshape(&self.reshaper, text, &xml::ANDROID),
+            CatalogFormat::Xliff => xml::reshape(&self.reshaper, text, &xml::XLIFF),
+        }
+    }
+}
+
+impl From<ReshaperConfig> for CatalogProcessor {
+    fn from(value: ReshaperConfig) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Split the text of the tokens to the text and the placeholders of the message
+fn with_placeholders<'a>(tokens: Vec<Token<'a>>) -> Vec<Token<'a>> {
+    let mut result = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        match token {
+            Text(text) => message_tokens(text, &mut result),
+            token => result.push(token),
+        }
+    }
+    result
+}
+
+/// Split the message to the text and the printf (`%s`, `%1$d`) and ICU (`{name}`,
+/// `{count, plural, one {# item} other {# items}}`) placeholders. The text inside the
+/// ICU plural and select messages is reshaped too.
+fn message_tokens<'a>(text: &'a str, result: &mut Vec<Token<'a>>) {
+    let bytes = text.as_bytes();
+    // depth of the ICU braces, the text is at the even depths and the syntax at the odd ones
+    let mut depth = 0;
+    let (mut start, mut idx) = (0, 0);
+
+    // push the text before `idx` and the placeholder of `len` bytes after it
+    let push = |result: &mut Vec<Token<'a>>, start: &mut usize, idx: usize, len: usize| {
+        if *start < idx {
+            result.push(Text(&text[*start..idx]));
+        }
+        if len > 0 {
+            result.push(Placeholder(&text[idx..idx + len]));
+        }
+        *start = idx + len;
+    };
+
+    while idx < bytes.len() {
+        let b = bytes[idx];
+
+        if depth % 2 == 1 {
+            // the syntax of an argument ends at the start of a message or the end of the argument
+            match b {
+                b'{' => depth += 1,
+                b'}' => depth -= 1,
+                _ => {}
+            }
+            if depth % 2 == 0 {
+                let argument = start;
+                push(result, &mut start, argument, idx + 1 - argument);
+            }
+            idx += 1;
+            continue;
+        }
+
+        let len = match b {
+            b'%' => printf_len(&text[idx..]),
+            b'{' => brace_len(&text[idx..]),
+            b'#' if depth > 0 => Some(1),
+            _ => None,
+        };
+        if let Some(len) = len {
+            push(result, &mut start, idx, len);
+            idx += len;
+            continue;
+        }
+
+        // the start of an argument with messages in it, or the end of a message
+        if (b == b'{' && text[idx..].contains('}')) || (b == b'}' && depth > 0) {
+            push(result, &mut start, idx, 0);
+            depth = if b == b'{' { depth + 1 } else { depth - 1 };
+        }
+        idx += 1;
+    }
+
+    if start < text.len() {
+        let rest = &text[start..];
+        result.push(if depth % 2 == 1 {
+            Placeholder(rest)
+        } else {
+            Text(rest)
+        });
+    }
+}
@@ -0,0 +1,106 @@
+//! gettext catalogs, the `msgstr` strings of the entries are reshaped.
+
+use alloc::{string::String, vec::Vec};
+
+use super::with_placeholders;
+use crate::{
+    markup::{reshape_tokens, tokens, Token},
+    reshaper::split_terminator,
+    ArabicReshaper,
+};
+
+/// Keyword of the string that is being read
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Msgid,
+    Msgstr,
+    Other,
+}
+
+pub(super) fn reshape(reshaper: &ArabicReshaper, text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    // the keyword and the start of the string that is being read
+    let mut string: Option<(Keyword, usize)> = None;
+    // the header is the entry with an empty `msgid`, its `msgstr` is the metadata of the catalog
+    let mut header = false;
+    let mut written = 0;
+    let mut position = 0;
+
+    // the empty line at the end ends the last string
+    for line in text.split_inclusive('\n').chain([""]) {
+        let (content, _) = split_terminator(line);
+        let trimmed = content.trim_start();
+        let line_start = position;
+        position += line.len();
+
+        if trimmed.starts_with('"') {
+            continue;
+        }
+
+        // the line is not a continuation, so the last string has ended
+        if let Some((keyword, start)) = string.take() {
+            let value = &text[start..line_start];
+            match keyword {
+                Keyword::Msgid => header = is_empty(value),
+                Keyword::Msgstr if !header => {
+                    result.push_str(&text[written..start]);
+                    reshape_tokens(reshaper, &string_tokens(value), &mut result);
+                    written = line_start;
+                }
+                _ => {}
+            }
+        }
+
+        let keyword = match trimmed.split([' ', '\t', '"']).next() {
+            Some("msgid") => Keyword::Msgid,
+            Some(word) if word == "msgstr" || word.starts_with("msgstr[") => Keyword::Msgstr,
+            Some("msgctxt" | "msgid_plural") => Keyword::Other,
+            _ => continue,
+        };
+        if let Some(quote) = trimmed.find('"') {
+            string = Some((keyword, line_start + content.len() - trimmed.len() + quote));
+        }
+    }
+
+    result.push_str(&text[written..]);
+    result
+}
+
+/// Check if the quoted strings are all empty
+fn is_empty(value: &str) -> bool {
+    value
+        .split_whitespace()
+        .all(|part| part.chars().all(|c| c == '"'))
+}
+
+/// Split the quoted strings of a value to tokens, the quotes and the space between the
+/// strings are tags and the escape sequences are placeholders, except `\n` that is a break.
+fn string_tokens(value: &str) -> Vec<Token<'_>> {
+    let mut in_string = false;
+
+    let result = tokens(value, |rest| match (in_string, rest.as_bytes()) {
+        (true, [b'\\', b'n', ..]) => Some((Token::Break(&rest[..2]), 2)),
+        (true, [b'\\', ..]) => {
+            let len = rest.chars().take(2).map(char::len_utf8).sum();
+            Some((Token::Placeholder(&rest[..len]), len))
+        }
+        (true, [b'"', ..]) => {
+            // the closing quote, the space after it and the opening quote of the next string
+            let len = match rest[1..].find('"') {
+                Some(next) => next + 2,
+                None => {
+                    in_string = false;
+                    rest.len()
+                }
+            };
+            Some((Token::Tag(&rest[..len]), len))
+        }
+        (false, [b'"', ..]) => {
+            in_string = true;
+            Some((Token::Tag(&rest[..1]), 1))
+        }
+        _ => None,
+    });
+
+    with_placeholders(result)
+}
@@ -0,0 +1,245 @@
+//! XML catalogs, the text of the `<string>` resources of Android and the `<target>`
+//! elements of XLIFF are reshaped.
+
+use alloc::{string::String, vec::Vec};
+
+use super::with_placeholders;
+use crate::{
+    markup::{entity_len, reshape_tokens, tokens, Token},
+    ArabicReshaper,
+};
+
+/// Elements of a catalog format that have the translated text
+pub(super) struct Schema {
+    /// Check if the text of the element should be reshaped, with the parent of the element
+    is_target: fn(&Element, Option<&Element>) -> bool,
+    /// Inline elements that are written as they are with their content, like `<xliff:g>`
+    placeholders: &'static [&'static str],
+    /// If the text has backslash escapes in it, like `\n` and `\'`
+    escapes: bool,
+}
+
+pub(super) const ANDROID: Schema = Schema {
+    is_target: |element, parent| match element.name {
+        "string" => element.is_translatable(),
+        "item" => parent.is_some_and(|parent| {
+            matches!(parent.name, "plurals" | "string-array") && parent.is_translatable()
+        }),
+        _ => false,
+    },
+    placeholders: &["xliff:g"],
+    escapes: true,
+};
+
+pub(super) const XLIFF: Schema = Schema {
+    is_target: |element, _| element.name == "target",
+    placeholders: &["ph", "bpt", "ept", "it"],
+    escapes: false,
+};
+
+/// Start tag of an element
+pub(super) struct Element<'a> {
+    name: &'a str,
+    attributes: &'a str,
+}
+
+impl Element<'_> {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        let mut rest = self.attributes;
+
+        loop {
+            rest = rest.trim_start();
+            let (key, value) = rest.split_once('=')?;
+            let value = value.trim_start();
+            let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+            let (value, after) = value[1..].split_once(quote)?;
+
+            if key.trim_end() == name {
+                return Some(value);
+            }
+            rest = after;
+        }
+    }
+
+    fn is_translatable(&self) -> bool {
+        self.attribute("translatable") != Some("false")
+    }
+}
+
+enum Markup<'a> {
+    /// Start tag and if it's self-closing
+    Start(Element<'a>, bool),
+    End(&'a str),
+    /// Start of a CDATA section, its text is not parsed until `]]>`
+    CData,
+    /// Comments, processing instructions and declarations
+    Other,
+}
+
+/// Parse the markup at the start of the text and find its length, the text starts with `<`
+fn markup(text: &str) -> Option<(Markup<'_>, usize)> {
+    let rest = &text[1..];
+    let until = |pattern: &str| {
+        text.find(pattern)
+            .map_or(text.len(), |end| end + pattern.len())
+    };
+
+    if rest.starts_with("!--") {
+        return Some((Markup::Other, until("-->")));
+    } else if rest.starts_with("![CDATA[") {
+        return Some((Markup::CData, "<![CDATA[".len()));
+    } else if rest.starts_with(['?', '!']) {
+        return Some((Markup::Other, until(">")));
+    }
+
+    let (closing, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    if !rest.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == ':') {
+        return None;
+    }
+    let name_len = rest
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .unwrap_or(rest.len());
+
+    // the `>` can be inside the value of an attribute
+    let mut quote = None;
+    let end = rest.find(|c| match quote {
+        Some(q) if q == c => {
+            quote = None;
+            false
+        }
+        Some(_) => false,
+        None if c == '"' || c == '\'' => {
+            quote = Some(c);
+            false
+        }
+        None => c == '>',
+    })?;
+
+    let len = text.len() - rest.len() + end + 1;
+    let name = &rest[..name_len];
+    if closing {
+        return Some((Markup::End(name), len));
+    }
+
+    let attributes = &rest[name_len..end];
+    let element = Element {
+        name,
+        attributes: attributes.trim_end_matches('/'),
+    };
+    Some((Markup::Start(element, attributes.ends_with('/')), len))
+}
+
+/// Length of the text until the end of the CDATA section that starts at the start of the text
+fn cdata_len(text: &str) -> usize {
+    text.find("]]>").map_or(text.len(), |end| end + "]]>".len())
+}
+
+/// Find the end tag of the element with the given name that its content starts at the
+/// start of the text, return the start and the end of the end tag.
+fn end_tag(text: &str, name: &str) -> Option<(usize, usize)> {
+    let mut depth = 0;
+    let mut idx = 0;
+
+    while let Some(pos) = text[idx..].find('<') {
+        let start = idx + pos;
+        idx = start + 1;
+
+        let Some((markup, len)) = markup(&text[start..]) else {
+            continue;
+        };
+        idx = start + len;
+        match markup {
+            Markup::Start(element, false) if element.name == name => depth += 1,
+            Markup::End(end) if end == name => {
+                if depth == 0 {
+                    return Some((start, idx));
+                }
+                depth -= 1;
+            }
+            Markup::CData => idx = start + cdata_len(&text[start..]),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+pub(super) fn reshape(reshaper: &ArabicReshaper, text: &str, schema: &Schema) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut parents: Vec<Element> = Vec::new();
+    let mut written = 0;
+    let mut idx = 0;
+
+    while let Some(pos) = text[idx..].find('<') {
+        let start = idx + pos;
+        idx = start + 1;
+
+        let Some((markup, len)) = markup(&text[start..]) else {
+            continue;
+        };
+        idx = start + len;
+        match markup {
+            Markup::Start(element, false) => {
+                if (schema.is_target)(&element, parents.last()) {
+                    if let Some((content_end, end)) = end_tag(&text[idx..], element.name) {
+                        let content = &text[idx..idx + content_end];
+                        result.push_str(&text[written..idx]);
+                        reshape_tokens(reshaper, &content_tokens(content, schema), &mut result);
+
+                        written = idx + content_end;
+                        idx += end;
+                        continue;
+                    }
+                }
+                parents.push(element);
+            }
+            Markup::End(_) => {
+                parents.pop();
+            }
+            Markup::CData => idx = start + cdata_len(&text[start..]),
+            _ => {}
+        }
+    }
+
+    result.push_str(&text[written..]);
+    result
+}
+
+/// Split the content of an element to tokens, inline tags are tags and the placeholder
+/// elements, self-closing elements, entities and escapes are placeholders.
+fn content_tokens<'a>(content: &'a str, schema: &Schema) -> Vec<Token<'a>> {
+    let result = tokens(content, |rest| match rest.as_bytes() {
+        [b'<', ..] => {
+            let (markup, len) = markup(rest)?;
+            let len = match markup {
+                Markup::Start(element, false) if schema.placeholders.contains(&element.name) => {
+                    len + end_tag(&rest[len..], element.name).map_or(0, |(_, end)| end)
+                }
+                Markup::Start(_, true) => len,
+                _ => return Some((Token::Tag(&rest[..len]), len)),
+            };
+            Some((Token::Placeholder(&rest[..len]), len))
+        }
+        [b']', b']', b'>', ..] => Some((Token::Tag(&rest[..3]), 3)),
+        [b'&', ..] => entity_len(rest).map(|len| (Token::Placeholder(&rest[..len]), len)),
+        [b'\\', b'n', ..] if schema.escapes => Some((Token::Break(&rest[..2]), 2)),
+        [b'\\', b'u', ..] if schema.escapes => {
+            let hex = rest[2..]
+                .bytes()
+                .take(4)
+                .take_while(u8::is_ascii_hexdigit)
+                .count();
+            Some((Token::Placeholder(&rest[..2 + hex]), 2 + hex))
+        }
+        [b'\\', ..] if schema.escapes => {
+            let len = rest.chars().take(2).map(char::len_utf8).sum();
+            Some((Token::Placeholder(&rest[..len]), len))
+        }
+        _ => None,
+    });
+
+    with_placeholders(result)
+}
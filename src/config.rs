@@ -1,4 +1,17 @@
-use crate::{form::LettersType, ligatures::*, ArabicReshaper};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::{form::Forms, letters::LetterTable};
+use crate::{
+    form::LettersType,
+    letters::letters_db::{
+        LETTERS_ARABIC, LETTERS_ARABIC_V2, LETTERS_KURDISH, LETTERS_PASHTO, LETTERS_SINDHI,
+        LETTERS_URDU,
+    },
+    ligatures::*,
+    ArabicReshaper,
+};
 
 /// Flags to enable some or all groups of ligatures
 #[derive(Debug, Clone, Copy)]
@@ -51,8 +64,10 @@ impl LigaturesFlags {
 }
 
 /// Supported languages
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// it's only `Copy` without `CustomTable`, and features should only add things
+#[cfg_attr(not(feature = "alloc"), allow(missing_copy_implementations))]
 pub enum Language {
     /// `Arabic` is default and recommended to work in most of the cases
     #[default]
@@ -62,9 +77,66 @@ pub enum Language {
     /// `Kurdish` if you are using Kurdish Sarchia font is recommended,
     /// work with both unicode and classic Arabic-Kurdish keybouard
     Kurdish,
+    /// `Urdu` write HEH as HEH GOAL, KAF as KEHEH and YEH as FARSI YEH, keep
+    /// HEH DOACHASHMEE joined in aspirated letters like `دھ` and use the Urdu
+    /// digits and full stop (`۔`)
+    Urdu,
+    /// `Pashto` letters that have no presentation forms are written using [FormsFallback]
+    Pashto,
+    /// `Sindhi` letters that have no presentation forms are written using [FormsFallback]
+    Sindhi,
     /// Custom language
     #[cfg_attr(feature = "serde", serde(skip))] // we can't serialize this
     Custom(&'static [LettersType]),
+    /// Custom language that own its letters, can be loaded at runtime and serialized.\
+    /// [Language] isn't `Copy` because of this variant, use [`clone`](Clone::clone) to copy it.
+    #[cfg(feature = "alloc")]
+    CustomTable(LetterTable),
+}
+
+impl Language {
+    /// All the letters of the language
+    pub const fn letters(&self) -> &[LettersType] {
+        match self {
+            Language::Arabic => &LETTERS_ARABIC,
+            Language::ArabicV2 => &LETTERS_ARABIC_V2,
+            Language::Kurdish => &LETTERS_KURDISH,
+            Language::Urdu => &LETTERS_URDU,
+            Language::Pashto => &LETTERS_PASHTO,
+            Language::Sindhi => &LETTERS_SINDHI,
+            Language::Custom(c) => c,
+            #[cfg(feature = "alloc")]
+            Language::CustomTable(table) => table.letters(),
+        }
+    }
+
+    /// Replace the characters that are written differently in the language,
+    /// `after_letter` is whatever the character come right after a letter.
+    pub(crate) const fn normalize(&self, c: char, after_letter: bool) -> char {
+        match (self, c) {
+            // ARABIC-INDIC DIGITS to EXTENDED ARABIC-INDIC DIGITS
+            (Language::Urdu, '\u{0660}'..='\u{0669}') => {
+                match char::from_u32(c as u32 - 0x0660 + 0x06F0) {
+                    Some(c) => c,
+                    None => c,
+                }
+            }
+            // ARABIC FULL STOP, only at the end of words so numbers and urls are kept as is
+            (Language::Urdu, '.') if after_letter => '\u{06D4}',
+            _ => c,
+        }
+    }
+
+    /// The letters that other letters are written as in the language, they are
+    /// restored instead of the other letters when unshaping.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn canonical_letters(&self) -> &'static [char] {
+        match self {
+            // FARSI YEH, KEHEH and HEH GOAL
+            Language::Urdu => &['\u{06CC}', '\u{06A9}', '\u{06C1}'],
+            _ => &[],
+        }
+    }
 }
 
 impl core::fmt::Display for Language {
@@ -73,12 +145,35 @@ impl core::fmt::Display for Language {
             Language::Arabic => "Arabic",
             Language::ArabicV2 => "ArabicV2",
             Language::Kurdish => "Kurdish",
+            Language::Urdu => "Urdu",
+            Language::Pashto => "Pashto",
+            Language::Sindhi => "Sindhi",
             Language::Custom(_) => "Custom",
+            #[cfg(feature = "alloc")]
+            Language::CustomTable(_) => "CustomTable",
         }
         .fmt(f)
     }
 }
 
+/// How to write the forms of the letters that have no presentation forms in Unicode,
+/// like most of the Pashto and Sindhi letters (see [`Forms::unencoded`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FormsFallback {
+    /// Keep the letter as is, they still join correctly with the letters around them
+    #[default]
+    Base,
+    /// Put a ZWJ (U+200D) on the joining sides of the letter, so fonts that
+    /// support GSUB pick the right form
+    Zwj,
+    /// Use the Private Use Area of the font starting at the given [`char`], the isolated,
+    /// initial, medial and final forms of the n-th letter of
+    /// [`LETTERS_WITHOUT_FORMS`](crate::letters::letters_db::LETTERS_WITHOUT_FORMS)
+    /// are at `start + 4 * n` to `start + 4 * n + 3`, the other letters are kept as is
+    PrivateUse(char),
+}
+
 /// Hold state of whatever some ligatures are enabled or not.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -111,6 +206,18 @@ impl Ligatures {
         ligatures
     }
 
+    /// Enable the default ligatures of the given language
+    pub const fn for_language(language: &Language) -> Self {
+        let mut ligatures = Self::default();
+
+        if let Language::Urdu = language {
+            ligatures.list[LigatureNames::ARABIC_LIGATURE_SALLALLAHOU_ALAYHE_WASALLAM as usize] =
+                true;
+        }
+
+        ligatures
+    }
+
     /// Is any ligature enabled
     const fn is_any_enabled(&self) -> bool {
         let mut idx = 0;
@@ -129,13 +236,40 @@ impl Ligatures {
     }
 }
 
+/// A ligature that is defined at runtime, for example to use the extra
+/// ligatures of a font that are in the Private Use Area.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "alloc")]
+pub struct CustomLigature {
+    /// The text that is replaced by the ligature
+    pub text: String,
+    /// The replacement for each form of the text, `'\0'` for the forms that
+    /// shouldn't be replaced
+    pub forms: Forms,
+}
+
+#[cfg(feature = "alloc")]
+impl CustomLigature {
+    /// Create a new [CustomLigature]
+    pub fn new<S>(text: S, forms: Forms) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            forms,
+        }
+    }
+}
+
 /// The main Config struct for the [ArabicReshaper]
 ///
 /// You can change all kinds of settings about [ArabicReshaper] using this struct.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReshaperConfig {
-    /// Supported languages are: **Arabic, ArabicV2, Kurdish**
+    /// Supported languages are: **Arabic, ArabicV2, Kurdish, Urdu, Pashto, Sindhi** and custom languages
     /// More languages might be supported soon.
     pub language: Language,
     /// Whether to delete the Harakat (Tashkeel) before reshaping or not.
@@ -157,6 +291,20 @@ pub struct ReshaperConfig {
     /// When `support_ligatures` is disabled,
     /// separate ligatures configurations are ignored.
     pub ligatures: Ligatures,
+    /// Ligatures that are defined at runtime, they take precedence over
+    /// the builtin ligatures and are ignored when `support_ligatures` is disabled.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom_ligatures: Vec<CustomLigature>,
+    /// How to write the letters that have no presentation forms
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub forms_fallback: FormsFallback,
+    /// Whether to reorder the reshaped text to visual order using the
+    /// Unicode Bidirectional Algorithm, so it can be shown by renderers
+    /// that only support left-to-right text.
+    #[cfg(feature = "bidi")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub visual_ordering: bool,
 }
 
 impl Default for ReshaperConfig {
@@ -182,6 +330,11 @@ impl Default for ReshaperConfig {
             use_unshaped_instead_of_isolated: false,
             support_ligatures: true,
             ligatures,
+            #[cfg(feature = "alloc")]
+            custom_ligatures: Vec::new(),
+            forms_fallback: FormsFallback::Base,
+            #[cfg(feature = "bidi")]
+            visual_ordering: false,
         }
     }
 }
@@ -190,7 +343,7 @@ impl ReshaperConfig {
     /// Create a new [ReshaperConfig] with the given [LigaturesFlags].
     pub const fn new(language: Language, ligatures_flags: LigaturesFlags) -> Self {
         let mut ligatures = if ligatures_flags.default_ligatures {
-            Ligatures::default()
+            Ligatures::for_language(&language)
         } else {
             Ligatures::empty()
         };
@@ -224,15 +377,24 @@ impl ReshaperConfig {
             }
         }
 
+        // the Urdu profile always writes its default ligatures
+        let support_ligatures = !ligatures_flags.is_none_enabled()
+            || ligatures_flags.default_ligatures && matches!(language, Language::Urdu);
+
         Self {
             language,
-            support_ligatures: !ligatures_flags.is_none_enabled(),
+            support_ligatures,
             ligatures,
+            #[cfg(feature = "alloc")]
+            custom_ligatures: Vec::new(),
+            forms_fallback: FormsFallback::Base,
             delete_harakat: true,
             shift_harakat_position: false,
             delete_tatweel: false,
             support_zwj: true,
             use_unshaped_instead_of_isolated: false,
+            #[cfg(feature = "bidi")]
+            visual_ordering: false,
         }
     }
 
@@ -251,19 +413,27 @@ impl ReshaperConfig {
         language: Language,
         ligatures_flags: LigaturesFlags,
     ) -> Result<Self, alloc::string::String> {
-        use crate::{form::Forms, letters::Letters};
-        use alloc::{string::ToString, vec::Vec};
-        use ttf_parser::Face;
+        use alloc::string::ToString;
 
-        let font = Face::parse(bytes, 0).map_err(|e| e.to_string())?;
+        let font = ttf_parser::Face::parse(bytes, 0).map_err(|e| e.to_string())?;
+        Ok(Self::from_face(&font, language, ligatures_flags))
+    }
 
+    /// Create a new [ReshaperConfig] based on a font that is already parsed, see
+    /// [`from_font`](Self::from_font).
+    #[cfg(feature = "ttf-parser")]
+    pub fn from_face(
+        font: &ttf_parser::Face,
+        language: Language,
+        ligatures_flags: LigaturesFlags,
+    ) -> Self {
         let mut config = Self {
             support_ligatures: !ligatures_flags.is_none_enabled(),
             ..Default::default()
         };
 
         if let Some(tables) = font.tables().cmap {
-            'top: for (_, v) in Letters::new(language).0 {
+            'top: for (_, v) in language.letters() {
                 for table in tables.subtables {
                     if v.isolated != '\0' && table.glyph_index(v.isolated as _).is_some() {
                         continue 'top;
@@ -273,7 +443,7 @@ impl ReshaperConfig {
                 break;
             }
 
-            let mut process_ligatures = |ligatures: &[(&'static [&'static str], Forms)]| {
+            let mut process_ligatures = |ligatures: &[(LigaturePattern, Forms)]| {
                 for (idx, (_, forms)) in ligatures.iter().enumerate() {
                     let forms: Vec<_> = [forms.isolated, forms.initial, forms.medial, forms.end]
                         .into_iter()
@@ -322,7 +492,7 @@ impl ReshaperConfig {
                 .for_each(|enabled| *enabled = false);
         }
 
-        Ok(config)
+        config
     }
 
     /// Update the given [LigatureNames].
@@ -330,6 +500,20 @@ impl ReshaperConfig {
         self.ligatures.list[name as usize] = enable;
         // enable or disable ligatures if anything is enabled
         self.support_ligatures = self.ligatures.is_any_enabled();
+        #[cfg(feature = "alloc")]
+        {
+            self.support_ligatures |= !self.custom_ligatures.is_empty();
+        }
+    }
+
+    /// Add a [CustomLigature] that replace the given text with the given forms.
+    #[cfg(feature = "alloc")]
+    pub fn add_ligature<S>(&mut self, text: S, forms: Forms)
+    where
+        S: Into<String>,
+    {
+        self.custom_ligatures.push(CustomLigature::new(text, forms));
+        self.support_ligatures = true;
     }
 }
 
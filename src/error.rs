@@ -0,0 +1,63 @@
+//! Errors of the [ArabicReshaper](crate::ArabicReshaper)
+
+/// Error returned by [`try_reshape`](crate::ArabicReshaper::try_reshape) when the
+/// letters of the language can't reshape the text, and by
+/// [`reshape_to_chars`](crate::ArabicReshaper::reshape_to_chars) and
+/// [`reshape_to_bytes`](crate::ArabicReshaper::reshape_to_bytes) when the text doesn't fit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReshapeError {
+    /// The letter is in the letters of the language but it doesn't have the form it needs
+    /// in the text, usually because of a malformed custom language.
+    MissingForm {
+        /// The letter without the form
+        letter: char,
+        /// Position (in bytes) of the letter in the text
+        position: usize,
+    },
+    /// The letter is used as a letter of the language but it's not in the letters.
+    MissingLetter {
+        /// The letter that is not in the letters
+        letter: char,
+        /// Position (in bytes) of the letter in the text
+        position: usize,
+    },
+    /// The buffer is too small for the reshaped text.
+    BufferTooSmall {
+        /// Length that the buffer needs, in the same unit as the buffer (chars or bytes)
+        needed: usize,
+    },
+    /// A part of the text that has to be reshaped at once (letters that join each
+    /// other and the ligatures around them) is too long to be kept on the stack.
+    SegmentTooLong {
+        /// Position (in bytes) of the character that didn't fit
+        position: usize,
+    },
+}
+
+impl core::fmt::Display for ReshapeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReshapeError::MissingForm { letter, position } => write!(
+                f,
+                "letter {letter:?} (U+{:04X}) at {position} doesn't have the form it needs",
+                *letter as u32
+            ),
+            ReshapeError::MissingLetter { letter, position } => write!(
+                f,
+                "letter {letter:?} (U+{:04X}) at {position} is not in the letters",
+                *letter as u32
+            ),
+            ReshapeError::BufferTooSmall { needed } => {
+                write!(f, "the buffer is too small, {needed} is needed")
+            }
+            ReshapeError::SegmentTooLong { position } => {
+                write!(
+                    f,
+                    "the text at {position} is too long to reshape without allocating"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ReshapeError {}
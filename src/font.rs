@@ -0,0 +1,222 @@
+//! Fonts
+//!
+//! Measure the reshaped text with the glyphs of the font that the reshaper is created for,
+//! so labels can be sized without another font library. Only the advances of the glyphs
+//! and the pair kerning (of the `GPOS` or `kern` table) are used, the text is not shaped
+//! with the other features of the font.
+
+use alloc::{string::String, vec::Vec};
+
+use ttf_parser::{
+    gpos::{PairAdjustment, PositioningSubtable},
+    Face, GlyphId, Tag,
+};
+
+use crate::{
+    config::{Language, LigaturesFlags},
+    reshaper::is_haraka,
+    ArabicReshaper, ReshaperConfig,
+};
+
+/// A font and the [ArabicReshaper] that only uses the presentation forms
+/// and ligatures that are in it.
+/// ```rust,no_run
+/// use ar_reshaper::{config::LigaturesFlags, font::ShapedFont, Language};
+///
+/// let bytes = std::fs::read("font.ttf").unwrap();
+/// let font = ShapedFont::new(&bytes, Language::Arabic, LigaturesFlags::default()).unwrap();
+///
+/// let reshaped = font.reshaper().reshape("سلام دنیا");
+/// let width = font.measure(&reshaped) / font.face().units_per_em() as f32 * 16.0;
+/// println!("{width}px at 16px, missing glyphs: {:?}", font.missing_glyphs(&reshaped));
+/// ```
+#[derive(Clone)]
+pub struct ShapedFont<'a> {
+    face: Face<'a>,
+    reshaper: ArabicReshaper,
+    /// The lookups of the `kern` feature of the `GPOS` table
+    kern_lookups: Vec<u16>,
+}
+
+impl<'a> ShapedFont<'a> {
+    /// Parse the font and create a reshaper for it, the same as [`ReshaperConfig::from_font`]
+    pub fn new(
+        bytes: &'a [u8],
+        language: Language,
+        ligatures_flags: LigaturesFlags,
+    ) -> Result<Self, String> {
+        use alloc::string::ToString;
+
+        let face = Face::parse(bytes, 0).map_err(|e| e.to_string())?;
+        Ok(Self::from_face(face, language, ligatures_flags))
+    }
+
+    /// Create a reshaper for the font that is already parsed, the same as [`ReshaperConfig::from_face`]
+    pub fn from_face(face: Face<'a>, language: Language, ligatures_flags: LigaturesFlags) -> Self {
+        let reshaper =
+            ArabicReshaper::new(ReshaperConfig::from_face(&face, language, ligatures_flags));
+
+        let mut kern_lookups = Vec::new();
+        if let Some(gpos) = face.tables().gpos {
+            for feature in gpos.features {
+                if feature.tag == Tag::from_bytes(b"kern") {
+                    kern_lookups.extend(feature.lookup_indices);
+                }
+            }
+            kern_lookups.sort_unstable();
+            kern_lookups.dedup();
+        }
+
+        Self {
+            face,
+            reshaper,
+            kern_lookups,
+        }
+    }
+
+    /// The parsed font
+    pub fn face(&self) -> &Face<'a> {
+        &self.face
+    }
+
+    /// The reshaper that is created for the font
+    pub fn reshaper(&self) -> &ArabicReshaper {
+        &self.reshaper
+    }
+
+    /// Width of the reshaped text in the units of the font (see
+    /// [`units_per_em`](Face::units_per_em)), the text should be in logical order like
+    /// the text that [`reshape`](ArabicReshaper::reshape) returns.
+    ///
+    /// The characters that are missing from the font are measured as the `.notdef`
+    /// glyph that is drawn for them, and the invisible characters (like ZWJ) have no width.
+    pub fn measure(&self, reshaped: &str) -> f32 {
+        let mut width = 0.0;
+        // the last glyph that isn't a mark, and if it's right-to-left
+        let mut previous: Option<(GlyphId, bool)> = None;
+
+        for c in reshaped.chars() {
+            if is_invisible(c) {
+                continue;
+            }
+
+            let glyph = self.glyph(c).unwrap_or(GlyphId(0));
+            width += self.face.glyph_hor_advance(glyph).map_or(0.0, f32::from);
+            if is_haraka(c) {
+                continue;
+            }
+
+            let rtl = is_rtl(c);
+            if let Some((previous, previous_rtl)) = previous {
+                width += f32::from(self.kerning(previous, glyph, rtl && previous_rtl));
+            }
+            previous = Some((glyph, rtl));
+        }
+
+        width
+    }
+
+    /// Find the characters of the reshaped text that are not in the font and are
+    /// drawn as the `.notdef` glyph, with their position (in bytes) in the text.
+    /// ```rust,no_run
+    /// # use ar_reshaper::{config::LigaturesFlags, font::ShapedFont, Language};
+    /// # let bytes = std::fs::read("font.ttf").unwrap();
+    /// let font = ShapedFont::new(&bytes, Language::Arabic, LigaturesFlags::default()).unwrap();
+    ///
+    /// for (position, c) in font.missing_glyphs(&font.reshaper().reshape("سلام")) {
+    ///     println!("U+{:04X} at {position} is missing", c as u32);
+    /// }
+    /// ```
+    pub fn missing_glyphs(&self, reshaped: &str) -> Vec<(usize, char)> {
+        reshaped
+            .char_indices()
+            .filter(|(_, c)| !is_invisible(*c) && self.glyph(*c).is_none())
+            .collect()
+    }
+
+    /// The glyph of the character, [`None`] if it's `.notdef`
+    fn glyph(&self, c: char) -> Option<GlyphId> {
+        self.face.glyph_index(c).filter(|glyph| glyph.0 != 0)
+    }
+
+    /// Kerning between the glyphs, `first` comes before `second` in the text.
+    ///
+    /// The pairs of `GPOS` are in the order of the text, and the pairs of the `kern`
+    /// table are from left to right so they are swapped in right-to-left text.
+    fn kerning(&self, first: GlyphId, second: GlyphId, rtl: bool) -> i16 {
+        if !self.kern_lookups.is_empty() {
+            return self.gpos_kerning(first, second);
+        }
+
+        let Some(kern) = self.face.tables().kern else {
+            return 0;
+        };
+        let (left, right) = match rtl {
+            true => (second, first),
+            false => (first, second),
+        };
+
+        kern.subtables
+            .into_iter()
+            .filter(|subtable| subtable.horizontal && !subtable.has_cross_stream)
+            .filter_map(|subtable| subtable.glyphs_kerning(left, right))
+            .fold(0, i16::saturating_add)
+    }
+
+    /// Kerning of the pair adjustments of the `kern` feature of `GPOS`
+    fn gpos_kerning(&self, first: GlyphId, second: GlyphId) -> i16 {
+        let Some(gpos) = self.face.tables().gpos else {
+            return 0;
+        };
+
+        let mut kerning: i16 = 0;
+        for lookup in self
+            .kern_lookups
+            .iter()
+            .filter_map(|idx| gpos.lookups.get(*idx))
+        {
+            // the first subtable that has the pair is used
+            let value = lookup
+                .subtables
+                .into_iter::<PositioningSubtable>()
+                .find_map(|subtable| match subtable {
+                    PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => {
+                        let set = sets.get(coverage.get(first)?)?;
+                        set.get(second).map(|(value, _)| value.x_advance)
+                    }
+                    PositioningSubtable::Pair(PairAdjustment::Format2 {
+                        coverage,
+                        classes,
+                        matrix,
+                    }) => {
+                        if !coverage.contains(first) {
+                            return None;
+                        }
+                        let classes = (classes.0.get(first), classes.1.get(second));
+                        matrix.get(classes).map(|(value, _)| value.x_advance)
+                    }
+                    _ => None,
+                });
+
+            kerning = kerning.saturating_add(value.unwrap_or(0));
+        }
+        kerning
+    }
+}
+
+/// Check if the character is a control or format character that isn't drawn
+fn is_invisible(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2069}' | '\u{FEFF}'
+        )
+}
+
+/// Check if the character is written from right to left
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+    )
+}
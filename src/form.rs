@@ -13,6 +13,23 @@ pub(crate) enum LetterForm {
     Unshaped,
 }
 
+/// How a letter joins with the letters around it, the joining types of Unicode `ArabicShaping.txt`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoiningType {
+    /// `U`, doesn't join with other letters
+    NonJoining,
+    /// `R`, only join with the letter before it
+    RightJoining,
+    /// `L`, only join with the letter after it
+    LeftJoining,
+    /// `D`, join with the letters before and after it
+    DualJoining,
+    /// `C`, cause the letters around it to join, like tatweel and ZWJ
+    JoinCausing,
+    /// `T`, skipped when joining, like harakat
+    Transparent,
+}
+
 /// The main type used to show letter form in each position
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -33,13 +50,54 @@ impl Forms {
         }
     }
 
-    pub(crate) const fn get(&self, form: LetterForm) -> char {
-        match form {
+    /// Forms of a letter that joins with other letters but has no presentation forms.
+    ///
+    /// A form that is the letter itself means that there is no presentation form
+    /// for it, the reshaper write these forms using the
+    /// [`FormsFallback`](crate::config::FormsFallback) of the config.
+    ///
+    /// `dual_joining` letters connect to the letters before and after them,
+    /// the others only connect to the letter before them.
+    pub const fn unencoded(letter: char, dual_joining: bool) -> Self {
+        match dual_joining {
+            true => Self::new(letter, letter, letter, letter),
+            false => Self::new(letter, '\0', '\0', letter),
+        }
+    }
+
+    /// The [`JoiningType`] that these forms describe
+    pub const fn joining_type(&self) -> JoiningType {
+        let (before, after) = (
+            self.end != '\0' || self.medial != '\0',
+            self.initial != '\0' || self.medial != '\0',
+        );
+
+        match (before, after) {
+            (true, true) => JoiningType::DualJoining,
+            (true, false) => JoiningType::RightJoining,
+            (false, true) => JoiningType::LeftJoining,
+            (false, false) => JoiningType::NonJoining,
+        }
+    }
+
+    /// Check if the given [`char`] is one of the forms
+    pub fn contains(&self, c: char) -> bool {
+        c != '\0' && [self.isolated, self.initial, self.medial, self.end].contains(&c)
+    }
+
+    /// The given form, [`None`] if there is no such form
+    pub(crate) const fn get(&self, form: LetterForm) -> Option<char> {
+        let c = match form {
             LetterForm::Isolated => self.isolated,
             LetterForm::Initial => self.initial,
             LetterForm::Medial => self.medial,
             LetterForm::Final => self.end,
-            _ => panic!("Unsupported Letter form"),
+            LetterForm::Unsupported | LetterForm::Unshaped => return None,
+        };
+
+        match c {
+            '\0' => None,
+            c => Some(c),
         }
     }
 }
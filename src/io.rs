@@ -0,0 +1,274 @@
+//! Reshaping readers and writers
+//!
+//! Adapters for [`std::io`] that reshape the text line by line, so files and pipes
+//! can be reshaped without reading all of them. Line terminators (`\n` or `\r\n`)
+//! are kept as they are, and text that isn't valid UTF-8 is reported as an
+//! [`io::ErrorKind::InvalidData`] error.
+
+use std::{
+    io::{self, BufRead, Read, Write},
+    string::String,
+    vec::Vec,
+};
+
+use crate::{reshaper::split_terminator, ArabicReshaper, ReshapeBuffer, ReshaperConfig};
+
+fn to_str(line: &[u8]) -> io::Result<&str> {
+    core::str::from_utf8(line).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            std::format!("the text is not valid UTF-8: {e}"),
+        )
+    })
+}
+
+/// A writer that reshapes the text before writing it to the underlying writer.
+///
+/// The text is kept until a whole line is written, the last line is written when
+/// [`finish`](Self::finish) is called or the writer is dropped, even if it doesn't end
+/// with a line terminator.
+/// ```rust
+/// use std::io::Write;
+/// use ar_reshaper::io::ReshapingWriter;
+///
+/// let mut writer = ReshapingWriter::new(Vec::new());
+/// write!(writer, "سلام\r\nدنیا").unwrap();
+///
+/// let output = writer.finish().unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "ﺳﻼﻡ\r\nﺩﻧﯿﺎ");
+/// ```
+pub struct ReshapingWriter<W: Write> {
+    reshaper: ArabicReshaper,
+    /// Only [`None`] after [`finish`](Self::finish) is called
+    inner: Option<W>,
+    /// Text of the line that is not written yet
+    line: Vec<u8>,
+    /// Reshaped text that the underlying writer didn't take yet
+    output: Vec<u8>,
+    buffer: ReshapeBuffer,
+}
+
+impl<W: Write> ReshapingWriter<W> {
+    /// Create a new [ReshapingWriter] with the default [ArabicReshaper] config
+    pub fn new(inner: W) -> Self {
+        Self::with_config(ReshaperConfig::default(), inner)
+    }
+
+    /// Create a new [ReshapingWriter] using the given config
+    pub fn with_config(config: ReshaperConfig, inner: W) -> Self {
+        Self {
+            reshaper: ArabicReshaper::new(config),
+            inner: Some(inner),
+            line: Vec::new(),
+            output: Vec::new(),
+            buffer: ReshapeBuffer::new(),
+        }
+    }
+
+    /// Get a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("the writer is not finished")
+    }
+
+    /// Get a mutable reference to the underlying writer
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().expect("the writer is not finished")
+    }
+
+    /// Write the last line even if it doesn't end with a line terminator, flush
+    /// the underlying writer and return it.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_rest()?;
+        self.get_mut().flush()?;
+        Ok(self.inner.take().expect("the writer is not finished"))
+    }
+
+    /// Reshape and write the first `end` bytes of the line, the line is kept if none of it
+    /// could be written. If only a part of it is written, the rest is written before the next line.
+    fn write_line(&mut self, end: usize) -> io::Result<()> {
+        let line = match to_str(&self.line[..end]) {
+            Ok(line) => line,
+            Err(e) => {
+                // the line is removed when it's not valid, so it's only reported once
+                self.line.drain(..end);
+                return Err(e);
+            }
+        };
+        let (content, terminator) = split_terminator(line);
+        let reshaped = self.buffer.reshape(&self.reshaper, content);
+        self.output.extend_from_slice(reshaped.as_bytes());
+        self.output.extend_from_slice(terminator.as_bytes());
+        let len = self.output.len();
+
+        match self.write_output() {
+            Err(e) if self.output.len() == len => {
+                self.output.clear();
+                Err(e)
+            }
+            // the error is returned when the rest of the line is written
+            _ => {
+                self.line.drain(..end);
+                Ok(())
+            }
+        }
+    }
+
+    /// Write the reshaped text that is left from the last line
+    fn write_output(&mut self) -> io::Result<()> {
+        let inner = self.inner.as_mut().expect("the writer is not finished");
+        while !self.output.is_empty() {
+            match inner.write(&self.output) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => drop(self.output.drain(..n)),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_rest(&mut self) -> io::Result<()> {
+        self.write_output()?;
+        match self.line.is_empty() {
+            true => Ok(()),
+            false => self.write_line(self.line.len()),
+        }
+    }
+}
+
+impl<W: Write> Write for ReshapingWriter<W> {
+    /// Write the lines of `buf` one by one, if a line can't be written after
+    /// some lines are written, only the bytes of the written lines are returned.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_output()?;
+        let mut written = 0;
+
+        while let Some(pos) = buf[written..].iter().position(|b| *b == b'\n') {
+            let end = written + pos + 1;
+            let kept = self.line.len();
+            self.line.extend_from_slice(&buf[written..end]);
+            if let Err(e) = self.write_line(self.line.len()) {
+                // nothing of `buf` is kept for a line that isn't written
+                self.line.truncate(kept);
+                return match written {
+                    0 => Err(e),
+                    _ => Ok(written),
+                };
+            }
+            written = end;
+        }
+        self.line.extend_from_slice(&buf[written..]);
+
+        Ok(buf.len())
+    }
+
+    /// Flush the underlying writer, the last line is not written until it's finished
+    /// because reshaping it now could give the wrong form to its last letter.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_output()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for ReshapingWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // errors can't be reported while dropping, use `finish` to see them
+            let _ = self.write_rest();
+        }
+    }
+}
+
+/// A reader that reshapes the lines of the underlying reader.
+///
+/// It implements [BufRead], so the reshaped lines can be read with
+/// [`read_line`](BufRead::read_line) or [`lines`](BufRead::lines).
+/// ```rust
+/// use std::io::BufRead;
+/// use ar_reshaper::io::ReshapingReader;
+///
+/// let reader = ReshapingReader::new("سلام\nدنیا\n".as_bytes());
+/// let lines: Vec<String> = reader.lines().collect::<Result<_, _>>().unwrap();
+///
+/// assert_eq!(lines, ["ﺳﻼﻡ", "ﺩﻧﯿﺎ"]);
+/// ```
+pub struct ReshapingReader<R: BufRead> {
+    reshaper: ArabicReshaper,
+    inner: R,
+    /// Line that is read from the underlying reader
+    raw: Vec<u8>,
+    /// The reshaped line and the position of the next byte that should be read
+    line: String,
+    position: usize,
+    buffer: ReshapeBuffer,
+}
+
+impl<R: BufRead> ReshapingReader<R> {
+    /// Create a new [ReshapingReader] with the default [ArabicReshaper] config
+    pub fn new(inner: R) -> Self {
+        Self::with_config(ReshaperConfig::default(), inner)
+    }
+
+    /// Create a new [ReshapingReader] using the given config
+    pub fn with_config(config: ReshaperConfig, inner: R) -> Self {
+        Self {
+            reshaper: ArabicReshaper::new(config),
+            inner,
+            raw: Vec::new(),
+            line: String::new(),
+            position: 0,
+            buffer: ReshapeBuffer::new(),
+        }
+    }
+
+    /// Get a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the underlying reader, the part of the line that is not read yet is lost
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: BufRead> Read for ReshapingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = {
+            let available = self.fill_buf()?;
+            let len = available.len().min(buf.len());
+            buf[..len].copy_from_slice(&available[..len]);
+            len
+        };
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for ReshapingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position >= self.line.len() {
+            self.raw.clear();
+            self.line.clear();
+            self.position = 0;
+
+            if self.inner.read_until(b'\n', &mut self.raw)? > 0 {
+                let (content, terminator) = split_terminator(to_str(&self.raw)?);
+                self.buffer
+                    .reshape_into(&self.reshaper, content, &mut self.line);
+                self.line.push_str(terminator);
+            }
+        }
+
+        Ok(&self.line.as_bytes()[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = (self.position + amt).min(self.line.len());
+    }
+}
@@ -1,6 +1,13 @@
-use alloc::string::String;
+use alloc::{borrow::Cow, collections::VecDeque, string::String, vec::Vec};
+use core::{iter::Fuse, marker::PhantomData, ops::Range};
 
-use crate::{ArabicReshaper, ReshaperConfig};
+use crate::{
+    letters::letters_db::{TATWEEL, ZWJ},
+    ligatures::{LIGATURES, LIGATURES_AUTOMATON},
+    reshaper::HARAKAT_RE,
+    segment::Scratch,
+    ArabicReshaper, ReshaperConfig,
+};
 
 /// Iterator for the [ArabicReshaper], you can use this type to iterate over
 /// strings in a [Iterator] and reshape them
@@ -24,6 +31,33 @@ where
     }
 }
 
+/// Iterator for the [ArabicReshaper] that yields a [Cow], the strings
+/// that don't need reshaping are returned as they are without copying them.
+pub struct ArabicReshaperCowIter<'a, I>
+where
+    I: Iterator,
+{
+    reshaper: ArabicReshaper,
+    underlying: I,
+    _text: PhantomData<Cow<'a, str>>,
+}
+
+impl<'a, I> Iterator for ArabicReshaperCowIter<'a, I>
+where
+    I: Iterator,
+    I::Item: Into<Cow<'a, str>>,
+{
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.underlying.next().map(|v| match v.into() {
+            Cow::Borrowed(text) => self.reshaper.reshape_cow(text),
+            Cow::Owned(text) if self.reshaper.is_unchanged(&text) => Cow::Owned(text),
+            Cow::Owned(text) => Cow::Owned(self.reshaper.reshape(text)),
+        })
+    }
+}
+
 /// Wrap an iterator to reshape strings
 pub trait ArabicReshaperExt: Iterator + Sized
 where
@@ -44,6 +78,283 @@ where
             underlying: self,
         }
     }
+
+    /// Reshape the iterator with the default [ArabicReshaper] config, the strings
+    /// that don't need reshaping are yielded without copying them.
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use ar_reshaper::prelude::*;
+    ///
+    /// let mut lines = ["سلام", "hello"].into_iter().reshape_cow_default();
+    ///
+    /// assert_eq!(lines.next(), Some(Cow::Owned("ﺳﻼﻡ".to_string())));
+    /// assert_eq!(lines.next(), Some(Cow::Borrowed("hello")));
+    /// ```
+    fn reshape_cow_default<'a>(self) -> ArabicReshaperCowIter<'a, Self>
+    where
+        Self::Item: Into<Cow<'a, str>>,
+    {
+        self.reshape_cow_with_config(ReshaperConfig::default())
+    }
+
+    /// Reshape the iterator using the given config, the strings
+    /// that don't need reshaping are yielded without copying them.
+    fn reshape_cow_with_config<'a>(self, config: ReshaperConfig) -> ArabicReshaperCowIter<'a, Self>
+    where
+        Self::Item: Into<Cow<'a, str>>,
+    {
+        ArabicReshaperCowIter {
+            reshaper: ArabicReshaper::new(config),
+            underlying: self,
+            _text: PhantomData,
+        }
+    }
 }
 
 impl<I: Iterator> ArabicReshaperExt for I where I::Item: AsRef<str> {}
+
+/// Iterator for the [ArabicReshaper] that reshapes the characters of the underlying
+/// iterator as they come, without waiting for the whole line.
+///
+/// Characters are kept until the next letter is seen and no enabled ligature can
+/// go over them, so only a few characters are buffered even for very long texts.
+/// The result is the same as reshaping the whole text, but it's never reordered
+/// to visual order because that needs the whole paragraph.
+pub struct ArabicReshaperChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    reshaper: ArabicReshaper,
+    underlying: Fuse<I>,
+    /// Length of the longest enabled ligature in characters
+    max_ligature: usize,
+    /// Characters that are not reshaped yet, the first `context` bytes are
+    /// already reshaped and only kept to find the form of the next letter
+    pending: String,
+    context: usize,
+    /// Characters of `pending` that are not removed while reshaping, with their position
+    letters: Vec<(Range<usize>, char)>,
+    /// Letters of `pending` that are between the two letters of a ligature
+    in_ligature: Vec<bool>,
+    scratch: Scratch,
+    chunk: String,
+    map: Vec<Range<usize>>,
+    reshaped: VecDeque<char>,
+}
+
+impl<I> ArabicReshaperChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    fn new(config: ReshaperConfig, underlying: I) -> Self {
+        #[cfg(feature = "bidi")]
+        let config = ReshaperConfig {
+            visual_ordering: false,
+            ..config
+        };
+
+        let mut max_ligature = 0;
+        if config.support_ligatures {
+            for (idx, (pattern, _)) in LIGATURES.iter().enumerate() {
+                if config.ligatures.list[idx] {
+                    max_ligature = max_ligature.max(pattern.max_len());
+                }
+            }
+            for ligature in &config.custom_ligatures {
+                max_ligature = max_ligature.max(ligature.text.chars().count());
+            }
+        }
+
+        Self {
+            reshaper: ArabicReshaper::new(config),
+            underlying: underlying.fuse(),
+            max_ligature,
+            pending: String::new(),
+            context: 0,
+            letters: Vec::new(),
+            in_ligature: Vec::new(),
+            scratch: Scratch::default(),
+            chunk: String::new(),
+            map: Vec::new(),
+            reshaped: VecDeque::new(),
+        }
+    }
+
+    /// Find the characters of `pending` that are kept while reshaping, and the
+    /// ligatures that can be found in them.
+    fn scan(&mut self) {
+        let config = self.reshaper.config();
+        let letters = self.reshaper.letters();
+
+        self.letters.clear();
+        for (idx, c) in self.pending.char_indices() {
+            let after_letter = self
+                .letters
+                .last()
+                .is_some_and(|(_, c)| letters.contains_key(c));
+            let source = idx..idx + c.len_utf8();
+            let c = config.language.normalize(c, after_letter);
+
+            let removed = HARAKAT_RE.iter().any(|h| h.contains(&c))
+                || c == TATWEEL && config.delete_tatweel
+                || c == ZWJ && !config.support_zwj;
+            if !removed {
+                self.letters.push((source, c));
+            }
+        }
+
+        self.in_ligature.clear();
+        self.in_ligature.resize(self.letters.len(), false);
+        if !config.support_ligatures {
+            return;
+        }
+
+        let in_ligature = &mut self.in_ligature;
+        let mut mark = |range: Range<usize>| {
+            // the first letter of a ligature can be reshaped on its own
+            in_ligature[range.start + 1..range.end].fill(true);
+        };
+
+        for ligature in &config.custom_ligatures {
+            let len = ligature.text.chars().count();
+            for start in 0..(self.letters.len() + 1).saturating_sub(len) {
+                if self.letters[start..start + len]
+                    .iter()
+                    .map(|(_, c)| *c)
+                    .eq(ligature.text.chars())
+                {
+                    mark(start..start + len);
+                }
+            }
+        }
+
+        LIGATURES_AUTOMATON.find_overlapping(self.letters.iter().map(|(_, c)| *c), |idx, range| {
+            if config.ligatures.list[idx] {
+                mark(range);
+            }
+        });
+    }
+
+    /// Find the first letter of `pending` that the text before it can be reshaped without it.
+    fn split_point(&self) -> Option<usize> {
+        let letters = self.reshaper.letters();
+        // the context letter is at the start of the letters
+        let first = if self.context > 0 { 2 } else { 1 };
+
+        (first..self.letters.len()).find(|&k| {
+            let (previous, current) = (self.letters[k - 1].1, self.letters[k].1);
+
+            // every ligature that can go over the letters should be seen
+            let seen = self.letters.len() + 1 >= k + self.max_ligature;
+
+            // ZWJ is removed after the next letter is seen, so it should stay with its neighbours
+            let zwj = previous == ZWJ || current == ZWJ;
+
+            // the form of a letter only depend on the letter before it, except
+            // when the letter before it can't be connected from both sides
+            let depends_on_context = letters.connects_with_letter_after(previous)
+                && letters.connects_with_letter_before(current)
+                && !letters.connects_with_letters_before_and_after(previous);
+
+            seen && !zwj && !depends_on_context && !self.in_ligature[k]
+        })
+    }
+
+    /// Reshape `pending` until the given letter, the context letter and the given letter are
+    /// reshaped too so the forms are right, but they are not added to the result.
+    fn reshape_until(&mut self, until: Option<usize>) {
+        let end = match until {
+            Some(k) => self.letters[k].0.end,
+            None => self.pending.len(),
+        };
+        let skip = until.map(|k| self.letters[k].0.start).unwrap_or(end);
+
+        self.chunk.clear();
+        self.map.clear();
+        // it can't fail when it's not strict
+        let _ = self.reshaper.reshape_impl(
+            &self.pending[..end],
+            Some(&mut self.map),
+            &[],
+            false,
+            &mut self.scratch,
+            &mut self.chunk,
+        );
+
+        let context = self.context;
+        self.reshaped.extend(
+            self.chunk
+                .chars()
+                .zip(&self.map)
+                .filter(|(_, source)| (context..skip).contains(&source.start))
+                .map(|(c, _)| c),
+        );
+
+        match until {
+            Some(k) => {
+                // keep the letter before the split as the context of the next letters
+                let context = self.letters[k - 1].0.clone();
+                self.context = context.len();
+                self.pending.replace_range(context.end..skip, "");
+                self.pending.replace_range(..context.start, "");
+            }
+            None => {
+                self.pending.clear();
+                self.context = 0;
+            }
+        }
+
+        self.scan();
+    }
+}
+
+impl<I> Iterator for ArabicReshaperChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.reshaped.pop_front() {
+                return Some(c);
+            }
+
+            if let Some(k) = self.split_point() {
+                self.reshape_until(Some(k));
+                continue;
+            }
+
+            match self.underlying.next() {
+                Some(c) => {
+                    self.pending.push(c);
+                    self.scan();
+                }
+                None if self.pending.len() > self.context => self.reshape_until(None),
+                None => return None,
+            }
+        }
+    }
+}
+
+/// Wrap an iterator to reshape characters
+pub trait ArabicReshaperCharsExt: Iterator<Item = char> + Sized {
+    /// Reshape the characters with the default [ArabicReshaper] config
+    /// ```rust
+    /// use ar_reshaper::prelude::*;
+    ///
+    /// let reshaped: String = "سلام دنیا".chars().reshape_chars_default().collect();
+    ///
+    /// assert_eq!(reshaped, "ﺳﻼﻡ ﺩﻧﯿﺎ");
+    /// ```
+    fn reshape_chars_default(self) -> ArabicReshaperChars<Self> {
+        ArabicReshaperChars::new(ReshaperConfig::default(), self)
+    }
+
+    /// Reshape the characters using the given config
+    fn reshape_chars_with_config(self, config: ReshaperConfig) -> ArabicReshaperChars<Self> {
+        ArabicReshaperChars::new(config, self)
+    }
+}
+
+impl<I: Iterator<Item = char>> ArabicReshaperCharsExt for I {}
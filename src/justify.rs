@@ -0,0 +1,263 @@
+//! Kashida justification
+//!
+//! Justify a line by inserting tatweel (kashida, U+0640) between the joined letters,
+//! so the line fills a target width. Kashidas are only inserted between letters that
+//! are joined in the reshaped text, never after the last letter of a word or inside a
+//! ligature (like lam-alef), and the points are chosen with a list of [KashidaRule]s.
+
+use core::ops::Range;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    form::Forms, letters::letters_db::TATWEEL, ligatures::LIGATURES, reshaper::is_haraka,
+    ArabicReshaper, ReshaperConfig,
+};
+
+const SEEN: [char; 5] = ['\u{0633}', '\u{0634}', '\u{0635}', '\u{0636}', '\u{069A}'];
+const HEH_DAL: [char; 8] = [
+    '\u{0629}', '\u{0647}', '\u{06BE}', '\u{06C1}', '\u{06C0}', '\u{062F}', '\u{0630}', '\u{0688}',
+];
+const ALEF: [char; 11] = [
+    '\u{0627}', '\u{0623}', '\u{0625}', '\u{0622}', '\u{0671}', '\u{0637}', '\u{0638}', '\u{0644}',
+    '\u{0643}', '\u{06A9}', '\u{06AF}',
+];
+const BEH: [char; 10] = [
+    '\u{0628}', '\u{062A}', '\u{062B}', '\u{0646}', '\u{064A}', '\u{06CC}', '\u{0626}', '\u{067E}',
+    '\u{0679}', '\u{0649}',
+];
+const WAW: [char; 6] = [
+    '\u{0648}', '\u{0624}', '\u{0639}', '\u{063A}', '\u{0642}', '\u{0641}',
+];
+const LAM: char = '\u{0644}';
+const LAM_ALEF: [char; 4] = ['\u{0627}', '\u{0623}', '\u{0625}', '\u{0622}'];
+
+/// A kind of point where a kashida can be inserted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KashidaRule {
+    /// After a tatweel that is already in the text
+    Tatweel,
+    /// After the seen and sad families (`س ش ص ض`)
+    AfterSeen,
+    /// Before the final form of teh marbuta, heh and dal (`ة ه د ذ`)
+    BeforeFinalHehDal,
+    /// Before the final form of alef, tah, lam, kaf and gaf (`ا ط ظ ل ك گ`)
+    BeforeFinalAlef,
+    /// Before the medial form of the beh family (`ب ت ث ن ی`)
+    BeforeMedialBeh,
+    /// Before the final form of waw, ain, qaf and feh (`و ع ق ف`)
+    BeforeFinalWaw,
+    /// Between any other joined letters
+    Joined,
+}
+
+impl KashidaRule {
+    /// All the rules, from the most preferred to the least
+    pub const ALL: [Self; 7] = [
+        Self::Tatweel,
+        Self::AfterSeen,
+        Self::BeforeFinalHehDal,
+        Self::BeforeFinalAlef,
+        Self::BeforeMedialBeh,
+        Self::BeforeFinalWaw,
+        Self::Joined,
+    ];
+
+    /// Check if the rule matches the point between the joined letters, `b` is written as `b_form`
+    fn matches(&self, a: char, b: char, b_form: char, b_forms: &Forms) -> bool {
+        let final_form = b_form == b_forms.end;
+        match self {
+            Self::Tatweel => a == TATWEEL,
+            Self::AfterSeen => SEEN.contains(&a),
+            Self::BeforeFinalHehDal => final_form && HEH_DAL.contains(&b),
+            Self::BeforeFinalAlef => final_form && ALEF.contains(&b),
+            Self::BeforeMedialBeh => b_form == b_forms.medial && BEH.contains(&b),
+            Self::BeforeFinalWaw => final_form && WAW.contains(&b),
+            Self::Joined => true,
+        }
+    }
+}
+
+/// Justify lines by inserting kashidas between the joined letters.
+/// ```rust
+/// use ar_reshaper::{justify::Justifier, ReshaperConfig};
+///
+/// let justifier = Justifier::new(ReshaperConfig::default());
+///
+/// // the line is 8 characters, a kashida is inserted in each word
+/// assert_eq!(justifier.justify("سلام دنیا", 10), "ﺳـﻼﻡ ﺩﻧﯿـﺎ");
+/// ```
+#[derive(Clone)]
+pub struct Justifier {
+    reshaper: ArabicReshaper,
+    rules: Vec<KashidaRule>,
+    #[cfg(feature = "bidi")]
+    visual_ordering: bool,
+}
+
+impl Justifier {
+    /// Create a new [Justifier] using the given config, with all the [KashidaRule]s.
+    ///
+    /// When `visual_ordering` is enabled the line is reordered after the kashidas are inserted.
+    pub fn new(config: ReshaperConfig) -> Self {
+        #[cfg(feature = "bidi")]
+        let (config, visual_ordering) = {
+            let visual_ordering = config.visual_ordering;
+            (
+                ReshaperConfig {
+                    visual_ordering: false,
+                    ..config
+                },
+                visual_ordering,
+            )
+        };
+
+        Self {
+            reshaper: ArabicReshaper::new(config),
+            rules: Vec::from(KashidaRule::ALL),
+            #[cfg(feature = "bidi")]
+            visual_ordering,
+        }
+    }
+
+    /// Only insert kashidas at the points of the given rules, the rules that come
+    /// first are preferred.
+    pub fn with_rules(mut self, rules: &[KashidaRule]) -> Self {
+        self.rules = rules.to_vec();
+        self
+    }
+
+    /// Reshape the line and insert kashidas so it's `width` characters long, harakat
+    /// are not counted. The line is only reshaped when it's already long enough or
+    /// there is nowhere to insert a kashida.
+    pub fn justify(&self, line: &str, width: usize) -> String {
+        let char_width = |c| match is_haraka(c) {
+            true => 0.0,
+            false => 1.0,
+        };
+        self.justify_with(line, width as f32, char_width)
+    }
+
+    /// Reshape the line and insert kashidas so it fills the given width, `char_width`
+    /// returns the width of each character of the reshaped line (including the tatweel).
+    pub fn justify_with<F>(&self, line: &str, width: f32, mut char_width: F) -> String
+    where
+        F: FnMut(char) -> f32,
+    {
+        let (reshaped, map) = self.reshaper.reshape_with_map(line);
+        let chars: Vec<char> = reshaped.chars().collect();
+
+        let line_width: f32 = chars.iter().map(|c| char_width(*c)).sum();
+        let kashida_width = char_width(TATWEEL);
+        let count = match kashida_width > 0.0 {
+            true => ((width - line_width) / kashida_width).max(0.0) as usize,
+            false => 0,
+        };
+
+        // number of kashidas that are inserted before each character
+        let mut kashidas = vec![0; chars.len() + 1];
+        let points = self.points(line, &chars, &map);
+        for idx in 0..count {
+            if points.is_empty() {
+                break;
+            }
+            kashidas[points[idx % points.len()]] += 1;
+        }
+
+        let mut result = String::with_capacity(reshaped.len() + count * TATWEEL.len_utf8());
+        for (c, n) in chars.iter().zip(&kashidas) {
+            result.extend(core::iter::repeat_n(TATWEEL, *n));
+            result.push(*c);
+        }
+
+        #[cfg(feature = "bidi")]
+        if self.visual_ordering {
+            return crate::bidi::visual_order(&result);
+        }
+
+        result
+    }
+
+    /// Find the best point of each word, the points are the index of the character
+    /// that the kashida is inserted before, sorted from the most preferred.
+    fn points(&self, line: &str, chars: &[char], map: &[Range<usize>]) -> Vec<usize> {
+        // the best point of the current word and the priority of its rule
+        let mut best: Option<(usize, usize)> = None;
+        let mut points = Vec::new();
+
+        for (idx, c) in chars.iter().enumerate() {
+            if c.is_whitespace() {
+                points.extend(best.take());
+                continue;
+            }
+
+            let Some(next) = (idx + 1..chars.len()).find(|next| !is_haraka(chars[*next])) else {
+                continue;
+            };
+            let (Some(a), Some(b)) = (
+                self.glyph(line, &map[idx], *c),
+                self.glyph(line, &map[next], chars[next]),
+            ) else {
+                continue;
+            };
+
+            // the letters are joined when `a` has a joining form after it and `b` before it
+            let joined = (a.forms.initial == *c || a.forms.medial == *c)
+                && (b.forms.medial == chars[next] || b.forms.end == chars[next]);
+            if !joined || a.last == LAM && LAM_ALEF.contains(&b.first) {
+                continue;
+            }
+
+            let priority = (self.rules.iter())
+                .position(|rule| rule.matches(a.last, b.first, chars[next], &b.forms));
+            if let Some(priority) = priority {
+                // the last point of the word wins between the points of the same rule
+                if best.is_none_or(|(_, best)| priority <= best) {
+                    best = Some((next, priority));
+                }
+            }
+        }
+        points.extend(best);
+
+        points.sort_by_key(|(idx, priority)| (*priority, *idx));
+        points.into_iter().map(|(idx, _)| idx).collect()
+    }
+
+    /// The letters and forms of a character of the reshaped text, [`None`] if it's
+    /// not written with a presentation form (except the tatweel that joins anyway).
+    fn glyph(&self, line: &str, range: &Range<usize>, c: char) -> Option<Glyph> {
+        let mut letters = line[range.clone()].chars().filter(|c| !is_haraka(*c));
+        let first = letters.next()?;
+        let last = letters.next_back();
+
+        let forms = match last {
+            None if c != first || first == TATWEEL => *self.reshaper.letters().get(&first)?,
+            None => return None,
+            Some(_) => {
+                let custom_ligatures = self.reshaper.config().custom_ligatures.iter();
+                (custom_ligatures.map(|ligature| ligature.forms))
+                    .chain(LIGATURES.iter().map(|(_, forms)| *forms))
+                    .find(|forms| forms.contains(c))?
+            }
+        };
+
+        Some(Glyph {
+            first,
+            last: last.unwrap_or(first),
+            forms,
+        })
+    }
+}
+
+impl From<ReshaperConfig> for Justifier {
+    fn from(value: ReshaperConfig) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A character of the reshaped text, the letters or ligature that it's written for
+struct Glyph {
+    first: char,
+    last: char,
+    forms: Forms,
+}
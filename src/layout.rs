@@ -0,0 +1,152 @@
+//! Line breaking
+//!
+//! Wrap paragraphs to a maximum width. The logical text is broken at the Unicode line
+//! break opportunities (so words are never split) and every line is reshaped on its
+//! own, so the letters at the edges of the lines get their final and initial forms.
+//! A line is measured after it's reshaped as a whole, so the ligatures and the kerning
+//! between its words are in its width.
+
+use core::ops::Range;
+
+use alloc::{string::String, vec::Vec};
+
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+#[cfg(feature = "ttf-parser")]
+use crate::font::ShapedFont;
+use crate::{ArabicReshaper, ReshaperConfig};
+
+/// A line of the wrapped paragraph
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    /// The reshaped text of the line, without the spaces and line terminator at its end
+    pub text: String,
+    /// Range of the line in the paragraph, including the spaces and line terminator
+    pub range: Range<usize>,
+    /// Width of the reshaped text
+    pub width: f32,
+}
+
+/// Wrap paragraphs to lines that fit a maximum width.
+/// ```rust
+/// use ar_reshaper::{layout::Layout, ReshaperConfig};
+///
+/// let layout = Layout::new(ReshaperConfig::default());
+/// let lines = layout.wrap("سلام دنیا", 5.0, |_| 1.0);
+///
+/// assert_eq!(lines[0].text, "ﺳﻼﻡ");
+/// assert_eq!(lines[1].text, "ﺩﻧﯿﺎ");
+/// ```
+#[derive(Clone)]
+pub struct Layout {
+    reshaper: ArabicReshaper,
+    #[cfg(feature = "bidi")]
+    visual_ordering: bool,
+}
+
+impl Layout {
+    /// Create a new [Layout] using the given config.
+    ///
+    /// When `visual_ordering` is enabled every line is reordered on its own after it's wrapped.
+    pub fn new(config: ReshaperConfig) -> Self {
+        #[cfg(feature = "bidi")]
+        let (config, visual_ordering) = {
+            let visual_ordering = config.visual_ordering;
+            (
+                ReshaperConfig {
+                    visual_ordering: false,
+                    ..config
+                },
+                visual_ordering,
+            )
+        };
+
+        Self {
+            reshaper: ArabicReshaper::new(config),
+            #[cfg(feature = "bidi")]
+            visual_ordering,
+        }
+    }
+
+    /// Wrap the paragraph to lines that are not wider than `max_width`, `advance` returns
+    /// the width of each character of the reshaped text. The line breaks in the text
+    /// always start a new line, and a word that is wider than `max_width` get a line of its own.
+    pub fn wrap<F>(&self, text: &str, max_width: f32, mut advance: F) -> Vec<Line>
+    where
+        F: FnMut(char) -> f32,
+    {
+        self.wrap_measured(text, max_width, |line| line.chars().map(&mut advance).sum())
+    }
+
+    /// Wrap the paragraph using the glyphs of the font and the kerning between them (see
+    /// [`ShapedFont::measure`]), `max_width` is in the units of the font.
+    #[cfg(feature = "ttf-parser")]
+    pub fn wrap_with_font(&self, text: &str, max_width: f32, font: &ShapedFont) -> Vec<Line> {
+        self.wrap_measured(text, max_width, |line| font.measure(line))
+    }
+
+    /// Wrap the paragraph, `measure` returns the width of a reshaped line
+    fn wrap_measured<F>(&self, text: &str, max_width: f32, mut measure: F) -> Vec<Line>
+    where
+        F: FnMut(&str) -> f32,
+    {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        // the longest line that fits, until the next break opportunity
+        let mut fitting: Option<Line> = None;
+
+        for (end, opportunity) in linebreaks(text) {
+            let mut line = self.line(text, start..end, &mut measure);
+            if line.width > max_width {
+                if let Some(fitting) = fitting.take() {
+                    start = fitting.range.end;
+                    lines.push(self.reorder(fitting));
+                    line = self.line(text, start..end, &mut measure);
+                }
+            }
+
+            match opportunity {
+                BreakOpportunity::Mandatory => {
+                    lines.push(self.reorder(line));
+                    start = end;
+                    fitting = None;
+                }
+                BreakOpportunity::Allowed => fitting = Some(line),
+            }
+        }
+
+        lines
+    }
+
+    /// Reshape and measure the line in the given range of the text
+    fn line<F>(&self, text: &str, range: Range<usize>, measure: &mut F) -> Line
+    where
+        F: FnMut(&str) -> f32,
+    {
+        let reshaped = self.reshaper.reshape(text[range.clone()].trim_end());
+        Line {
+            width: measure(&reshaped),
+            text: reshaped,
+            range,
+        }
+    }
+
+    /// Reorder the text of the line to visual order if it's enabled
+    fn reorder(&self, line: Line) -> Line {
+        #[cfg(feature = "bidi")]
+        if self.visual_ordering {
+            return Line {
+                text: crate::bidi::visual_order(&line.text),
+                ..line
+            };
+        }
+
+        line
+    }
+}
+
+impl From<ReshaperConfig> for Layout {
+    fn from(value: ReshaperConfig) -> Self {
+        Self::new(value)
+    }
+}
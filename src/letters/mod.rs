@@ -1,83 +1,305 @@
-use core::ops::Index;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
 
-use crate::{form::*, Language};
+use crate::{form::*, Language, ReshapeError};
 
 pub mod letters_db;
+pub mod unicode_db;
 
-/// The main letters type.
+/// First letter covered by the direct-indexed part of [`LettersIndex`]
+const BLOCK_START: u32 = 0x0600;
+/// Number of letters covered by the direct-indexed part of [`LettersIndex`] (U+0600 - U+06FF)
+const BLOCK_LEN: usize = 0x100;
+
+const LETTERS_ARABIC_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_ARABIC);
+const LETTERS_ARABIC_V2_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_ARABIC_V2);
+const LETTERS_KURDISH_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_KURDISH);
+const LETTERS_URDU_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_URDU);
+const LETTERS_PASHTO_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_PASHTO);
+const LETTERS_SINDHI_INDEX: LettersIndex = LettersIndex::new(&letters_db::LETTERS_SINDHI);
+
+/// Lookup table for the letters.
 ///
-/// this struct is responsible for managing all the letters data.
+/// Letters inside the Arabic block are direct-indexed, the few letters outside
+/// of it (like ZWJ) are found by a scan that is only done when the letter is in their range.
+#[derive(Clone, Copy)]
+pub(crate) struct LettersIndex {
+    /// Position + 1 of each letter of the Arabic block in the letters, `0` if the letter doesn't exist
+    block: [u16; BLOCK_LEN],
+    /// Range of the letters that are outside of the Arabic block, or can't be direct-indexed
+    others: (u32, u32),
+}
+
+impl LettersIndex {
+    const EMPTY: Self = Self {
+        block: [0; BLOCK_LEN],
+        others: (u32::MAX, 0),
+    };
+
+    const fn new(letters: &[LettersType]) -> Self {
+        let mut index = Self::EMPTY;
+
+        let mut idx = 0;
+        while idx < letters.len() {
+            index.add(letters[idx].0, idx);
+            idx += 1;
+        }
+
+        index
+    }
+
+    /// Create the index of the letters of the given [`Language`],
+    /// [`Language::CustomTable`] have its own index so it get an empty one.
+    pub const fn for_language(language: &Language) -> Self {
+        match language {
+            Language::Arabic => LETTERS_ARABIC_INDEX,
+            Language::ArabicV2 => LETTERS_ARABIC_V2_INDEX,
+            Language::Kurdish => LETTERS_KURDISH_INDEX,
+            Language::Urdu => LETTERS_URDU_INDEX,
+            Language::Pashto => LETTERS_PASHTO_INDEX,
+            Language::Sindhi => LETTERS_SINDHI_INDEX,
+            Language::Custom(c) => Self::new(c),
+            #[cfg(feature = "alloc")]
+            Language::CustomTable(_) => Self::EMPTY,
+        }
+    }
+
+    /// Add the letter that is in the given position of the letters
+    const fn add(&mut self, letter: char, idx: usize) {
+        let key = letter as u32;
+        if key.wrapping_sub(BLOCK_START) < BLOCK_LEN as u32 && idx < u16::MAX as usize {
+            let pos = (key - BLOCK_START) as usize;
+            // first one win, just like a linear search
+            if self.block[pos] == 0 {
+                self.block[pos] = idx as u16 + 1;
+            }
+        } else {
+            if key < self.others.0 {
+                self.others.0 = key;
+            }
+            if key > self.others.1 {
+                self.others.1 = key;
+            }
+        }
+    }
+
+    /// Find the position of the given [`char`] in the letters
+    const fn position(&self, letters: &[LettersType], key: char) -> Option<usize> {
+        let key = key as u32;
+        if key.wrapping_sub(BLOCK_START) < BLOCK_LEN as u32 {
+            if let pos @ 1.. = self.block[(key - BLOCK_START) as usize] {
+                return Some(pos as usize - 1);
+            }
+        }
+
+        if key >= self.others.0 && key <= self.others.1 {
+            let mut idx = 0;
+            while idx < letters.len() {
+                if letters[idx].0 as u32 == key {
+                    return Some(idx);
+                }
+                idx += 1;
+            }
+        }
+        None
+    }
+}
+
+/// A table of letters that own its data, can be used to load the
+/// letters of a [`Language::CustomTable`] at runtime.
+///
+/// You can create it from an iterator of letters (or a map of letters to forms)
+/// or start from an existing table and add letters to it.
+/// ```rust
+/// use ar_reshaper::{form::Forms, letters::{letters_db::LETTERS_ARABIC, LetterTable}};
+///
+/// let table = LetterTable::from(&LETTERS_ARABIC[..])
+///     .with_letter('\u{0750}', Forms::new('\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}'));
+///
+/// assert!(table.get('\u{0750}').is_some());
+/// ```
 #[derive(Clone)]
-pub(crate) struct Letters(pub &'static [LettersType]);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<LettersType>", into = "Vec<LettersType>")
+)]
+#[cfg(feature = "alloc")]
+pub struct LetterTable {
+    letters: Vec<LettersType>,
+    index: Box<LettersIndex>,
+}
+
+#[cfg(feature = "alloc")]
+impl LetterTable {
+    /// Create a new empty [`LetterTable`]
+    pub fn new() -> Self {
+        Self {
+            letters: Vec::new(),
+            index: Box::new(LettersIndex::EMPTY),
+        }
+    }
+
+    /// Add the letter with the given forms, replace the forms if the letter already exist.
+    pub fn insert(&mut self, letter: char, forms: Forms) {
+        match self.index.position(&self.letters, letter) {
+            Some(pos) => self.letters[pos].1 = forms,
+            None => {
+                self.index.add(letter, self.letters.len());
+                self.letters.push((letter, forms));
+            }
+        }
+    }
+
+    /// Add the letter with the given forms and return the table, useful to build a table.
+    pub fn with_letter(mut self, letter: char, forms: Forms) -> Self {
+        self.insert(letter, forms);
+        self
+    }
+
+    /// Try to get the forms of the given letter
+    pub fn get(&self, letter: char) -> Option<&Forms> {
+        self.index
+            .position(&self.letters, letter)
+            .map(|pos| &self.letters[pos].1)
+    }
 
-impl Default for Letters {
+    /// All the letters in the table
+    pub const fn letters(&self) -> &[LettersType] {
+        self.letters.as_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for LetterTable {
     fn default() -> Self {
-        Self(&letters_db::LETTERS_ARABIC)
+        Self::new()
     }
 }
 
-impl Letters {
-    /// Create a new [`Letters`] with the given [`Language`]
-    pub const fn new(language: Language) -> Self {
-        Self(match language {
-            Language::Arabic => &letters_db::LETTERS_ARABIC,
-            Language::ArabicV2 => &letters_db::LETTERS_ARABIC_V2,
-            Language::Kurdish => &letters_db::LETTERS_KURDISH,
-            Language::Custom(c) => c,
-        })
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for LetterTable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.letters).finish()
     }
+}
 
-    /// Change the language of letters after creating the [`Letters`]
-    pub fn change_language(&mut self, language: Language) {
-        self.0 = match language {
-            Language::Arabic => &letters_db::LETTERS_ARABIC,
-            Language::ArabicV2 => &letters_db::LETTERS_ARABIC_V2,
-            Language::Kurdish => &letters_db::LETTERS_KURDISH,
-            Language::Custom(c) => c,
-        };
+#[cfg(feature = "alloc")]
+impl PartialEq for LetterTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.letters == other.letters
     }
+}
 
-    /// Check if the given [`char`] exist in the letters
-    pub fn contains_key(&self, key: &char) -> bool {
-        self.0.iter().any(|(k, _)| k == key)
+#[cfg(feature = "alloc")]
+impl FromIterator<LettersType> for LetterTable {
+    fn from_iter<T: IntoIterator<Item = LettersType>>(iter: T) -> Self {
+        let mut table = Self::new();
+        for (letter, forms) in iter {
+            table.insert(letter, forms);
+        }
+        table
     }
+}
 
-    /// Try to get the forms corresponding the letters
-    pub fn get(&self, key: &char) -> Option<&Forms> {
-        self.0.iter().find(|(k, _)| k == key).map(|(_, f)| f)
+#[cfg(feature = "alloc")]
+impl From<Vec<LettersType>> for LetterTable {
+    fn from(value: Vec<LettersType>) -> Self {
+        value.into_iter().collect()
     }
+}
 
-    pub fn get_form(&self, letter: char, form: LetterForm) -> char {
-        if matches!(form, LetterForm::Unshaped | LetterForm::Unsupported) {
-            return letter;
-        }
+#[cfg(feature = "alloc")]
+impl From<&[LettersType]> for LetterTable {
+    fn from(value: &[LettersType]) -> Self {
+        value.iter().copied().collect()
+    }
+}
 
-        let forms = self
-            .get(&letter)
-            .expect("Can't find character inside letters");
+#[cfg(feature = "alloc")]
+impl From<LetterTable> for Vec<LettersType> {
+    fn from(value: LetterTable) -> Self {
+        value.letters
+    }
+}
 
-        forms.get(form)
+/// The main letters type.
+///
+/// this struct is responsible for managing all the letters data.
+#[derive(Clone, Copy)]
+pub(crate) struct Letters<'a> {
+    pub letters: &'a [LettersType],
+    index: &'a LettersIndex,
+}
+
+impl<'a> Letters<'a> {
+    /// Create a new [`Letters`] with the given [`Language`], the `index` should be
+    /// created by [`LettersIndex::for_language`] from the same language.
+    pub const fn new(language: &'a Language, index: &'a LettersIndex) -> Self {
+        match language {
+            #[cfg(feature = "alloc")]
+            Language::CustomTable(table) => Self {
+                letters: table.letters.as_slice(),
+                index: &table.index,
+            },
+            language => Self {
+                letters: language.letters(),
+                index,
+            },
+        }
     }
 
-    pub fn connects_with_letter_before(&self, letter: char) -> bool {
-        self.get(&letter)
-            .is_some_and(|forms| forms.end != '\0' || forms.medial != '\0')
+    /// Check if the given [`char`] exist in the letters
+    pub const fn contains_key(&self, key: &char) -> bool {
+        self.index.position(self.letters, *key).is_some()
+    }
+
+    /// Try to get the forms corresponding the letters
+    pub const fn get(&self, key: &char) -> Option<&'a Forms> {
+        match self.index.position(self.letters, *key) {
+            Some(pos) => Some(&self.letters[pos].1),
+            None => None,
+        }
     }
 
-    pub fn connects_with_letter_after(&self, letter: char) -> bool {
-        self.get(&letter)
-            .is_some_and(|forms| forms.initial != '\0' || forms.medial != '\0')
+    /// Get the given form of the letter, the position is only used for the errors
+    pub const fn get_form(
+        &self,
+        letter: char,
+        form: LetterForm,
+        position: usize,
+    ) -> Result<char, ReshapeError> {
+        if matches!(form, LetterForm::Unshaped | LetterForm::Unsupported) {
+            return Ok(letter);
+        }
+
+        match self.get(&letter) {
+            None => Err(ReshapeError::MissingLetter { letter, position }),
+            Some(forms) => match forms.get(form) {
+                Some(c) => Ok(c),
+                None => Err(ReshapeError::MissingForm { letter, position }),
+            },
+        }
     }
 
-    pub fn connects_with_letters_before_and_after(&self, letter: char) -> bool {
-        self.get(&letter).is_some_and(|forms| forms.medial != '\0')
+    pub const fn connects_with_letter_before(&self, letter: char) -> bool {
+        match self.get(&letter) {
+            Some(forms) => forms.end != '\0' || forms.medial != '\0',
+            None => false,
+        }
     }
-}
 
-impl Index<&char> for Letters {
-    type Output = Forms;
+    pub const fn connects_with_letter_after(&self, letter: char) -> bool {
+        match self.get(&letter) {
+            Some(forms) => forms.initial != '\0' || forms.medial != '\0',
+            None => false,
+        }
+    }
 
-    fn index(&self, index: &char) -> &Self::Output {
-        &self.0.iter().find(|(k, _)| k == index).unwrap().1
+    pub const fn connects_with_letters_before_and_after(&self, letter: char) -> bool {
+        match self.get(&letter) {
+            Some(forms) => forms.medial != '\0',
+            None => false,
+        }
     }
 }
@@ -0,0 +1,863 @@
+//! Joining types and presentation forms of the letters, from the Unicode Character Database.
+//!
+//! Generated by `cargo xtask tables` from the files in `data/`, don't edit by hand.
+
+use crate::form::{Forms, JoiningType, LettersType};
+
+/// Joining type of the letters in the Arabic blocks
+pub const JOINING_TYPES: [(char, JoiningType); 280] = [
+    // ARABIC NUMBER SIGN
+    ('\u{0600}', JoiningType::NonJoining),
+    // ARABIC SIGN SANAH
+    ('\u{0601}', JoiningType::NonJoining),
+    // ARABIC FOOTNOTE MARKER
+    ('\u{0602}', JoiningType::NonJoining),
+    // ARABIC SIGN SAFHA
+    ('\u{0603}', JoiningType::NonJoining),
+    // ARABIC SIGN SAMVAT
+    ('\u{0604}', JoiningType::NonJoining),
+    // ARABIC NUMBER MARK ABOVE
+    ('\u{0605}', JoiningType::NonJoining),
+    // ARABIC LETTER KASHMIRI YEH
+    ('\u{0620}', JoiningType::DualJoining),
+    // ARABIC LETTER HAMZA
+    ('\u{0621}', JoiningType::NonJoining),
+    // ARABIC LETTER ALEF WITH MADDA ABOVE
+    ('\u{0622}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH HAMZA ABOVE
+    ('\u{0623}', JoiningType::RightJoining),
+    // ARABIC LETTER WAW WITH HAMZA ABOVE
+    ('\u{0624}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH HAMZA BELOW
+    ('\u{0625}', JoiningType::RightJoining),
+    // ARABIC LETTER YEH WITH HAMZA ABOVE
+    ('\u{0626}', JoiningType::DualJoining),
+    // ARABIC LETTER ALEF
+    ('\u{0627}', JoiningType::RightJoining),
+    // ARABIC LETTER BEH
+    ('\u{0628}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH MARBUTA
+    ('\u{0629}', JoiningType::RightJoining),
+    // ARABIC LETTER TEH
+    ('\u{062A}', JoiningType::DualJoining),
+    // ARABIC LETTER THEH
+    ('\u{062B}', JoiningType::DualJoining),
+    // ARABIC LETTER JEEM
+    ('\u{062C}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH
+    ('\u{062D}', JoiningType::DualJoining),
+    // ARABIC LETTER KHAH
+    ('\u{062E}', JoiningType::DualJoining),
+    // ARABIC LETTER DAL
+    ('\u{062F}', JoiningType::RightJoining),
+    // ARABIC LETTER THAL
+    ('\u{0630}', JoiningType::RightJoining),
+    // ARABIC LETTER REH
+    ('\u{0631}', JoiningType::RightJoining),
+    // ARABIC LETTER ZAIN
+    ('\u{0632}', JoiningType::RightJoining),
+    // ARABIC LETTER SEEN
+    ('\u{0633}', JoiningType::DualJoining),
+    // ARABIC LETTER SHEEN
+    ('\u{0634}', JoiningType::DualJoining),
+    // ARABIC LETTER SAD
+    ('\u{0635}', JoiningType::DualJoining),
+    // ARABIC LETTER DAD
+    ('\u{0636}', JoiningType::DualJoining),
+    // ARABIC LETTER TAH
+    ('\u{0637}', JoiningType::DualJoining),
+    // ARABIC LETTER ZAH
+    ('\u{0638}', JoiningType::DualJoining),
+    // ARABIC LETTER AIN
+    ('\u{0639}', JoiningType::DualJoining),
+    // ARABIC LETTER GHAIN
+    ('\u{063A}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH TWO DOTS ABOVE
+    ('\u{063B}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH THREE DOTS BELOW
+    ('\u{063C}', JoiningType::DualJoining),
+    // ARABIC LETTER FARSI YEH WITH INVERTED V
+    ('\u{063D}', JoiningType::DualJoining),
+    // ARABIC LETTER FARSI YEH WITH TWO DOTS ABOVE
+    ('\u{063E}', JoiningType::DualJoining),
+    // ARABIC LETTER FARSI YEH WITH THREE DOTS ABOVE
+    ('\u{063F}', JoiningType::DualJoining),
+    // ARABIC TATWEEL
+    ('\u{0640}', JoiningType::JoinCausing),
+    // ARABIC LETTER FEH
+    ('\u{0641}', JoiningType::DualJoining),
+    // ARABIC LETTER QAF
+    ('\u{0642}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF
+    ('\u{0643}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM
+    ('\u{0644}', JoiningType::DualJoining),
+    // ARABIC LETTER MEEM
+    ('\u{0645}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON
+    ('\u{0646}', JoiningType::DualJoining),
+    // ARABIC LETTER HEH
+    ('\u{0647}', JoiningType::DualJoining),
+    // ARABIC LETTER WAW
+    ('\u{0648}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF MAKSURA
+    ('\u{0649}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH
+    ('\u{064A}', JoiningType::DualJoining),
+    // ARABIC LETTER DOTLESS BEH
+    ('\u{066E}', JoiningType::DualJoining),
+    // ARABIC LETTER DOTLESS QAF
+    ('\u{066F}', JoiningType::DualJoining),
+    // ARABIC LETTER ALEF WASLA
+    ('\u{0671}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH WAVY HAMZA ABOVE
+    ('\u{0672}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH WAVY HAMZA BELOW
+    ('\u{0673}', JoiningType::RightJoining),
+    // ARABIC LETTER HIGH HAMZA
+    ('\u{0674}', JoiningType::NonJoining),
+    // ARABIC LETTER HIGH HAMZA ALEF
+    ('\u{0675}', JoiningType::RightJoining),
+    // ARABIC LETTER HIGH HAMZA WAW
+    ('\u{0676}', JoiningType::RightJoining),
+    // ARABIC LETTER U WITH HAMZA ABOVE
+    ('\u{0677}', JoiningType::RightJoining),
+    // ARABIC LETTER HIGH HAMZA YEH
+    ('\u{0678}', JoiningType::DualJoining),
+    // ARABIC LETTER TTEH
+    ('\u{0679}', JoiningType::DualJoining),
+    // ARABIC LETTER TTEHEH
+    ('\u{067A}', JoiningType::DualJoining),
+    // ARABIC LETTER BEEH
+    ('\u{067B}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH WITH RING
+    ('\u{067C}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH WITH THREE DOTS ABOVE DOWNWARDS
+    ('\u{067D}', JoiningType::DualJoining),
+    // ARABIC LETTER PEH
+    ('\u{067E}', JoiningType::DualJoining),
+    // ARABIC LETTER TEHEH
+    ('\u{067F}', JoiningType::DualJoining),
+    // ARABIC LETTER BEHEH
+    ('\u{0680}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH HAMZA ABOVE
+    ('\u{0681}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH TWO DOTS VERTICAL ABOVE
+    ('\u{0682}', JoiningType::DualJoining),
+    // ARABIC LETTER NYEH
+    ('\u{0683}', JoiningType::DualJoining),
+    // ARABIC LETTER DYEH
+    ('\u{0684}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH THREE DOTS ABOVE
+    ('\u{0685}', JoiningType::DualJoining),
+    // ARABIC LETTER TCHEH
+    ('\u{0686}', JoiningType::DualJoining),
+    // ARABIC LETTER TCHEHEH
+    ('\u{0687}', JoiningType::DualJoining),
+    // ARABIC LETTER DDAL
+    ('\u{0688}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH RING
+    ('\u{0689}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH DOT BELOW
+    ('\u{068A}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH DOT BELOW AND SMALL TAH
+    ('\u{068B}', JoiningType::RightJoining),
+    // ARABIC LETTER DAHAL
+    ('\u{068C}', JoiningType::RightJoining),
+    // ARABIC LETTER DDAHAL
+    ('\u{068D}', JoiningType::RightJoining),
+    // ARABIC LETTER DUL
+    ('\u{068E}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH THREE DOTS ABOVE DOWNWARDS
+    ('\u{068F}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH FOUR DOTS ABOVE
+    ('\u{0690}', JoiningType::RightJoining),
+    // ARABIC LETTER RREH
+    ('\u{0691}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH SMALL V
+    ('\u{0692}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH RING
+    ('\u{0693}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH DOT BELOW
+    ('\u{0694}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH SMALL V BELOW
+    ('\u{0695}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH DOT BELOW AND DOT ABOVE
+    ('\u{0696}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH TWO DOTS ABOVE
+    ('\u{0697}', JoiningType::RightJoining),
+    // ARABIC LETTER JEH
+    ('\u{0698}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH FOUR DOTS ABOVE
+    ('\u{0699}', JoiningType::RightJoining),
+    // ARABIC LETTER SEEN WITH DOT BELOW AND DOT ABOVE
+    ('\u{069A}', JoiningType::DualJoining),
+    // ARABIC LETTER SEEN WITH THREE DOTS BELOW
+    ('\u{069B}', JoiningType::DualJoining),
+    // ARABIC LETTER SEEN WITH THREE DOTS BELOW AND THREE DOTS ABOVE
+    ('\u{069C}', JoiningType::DualJoining),
+    // ARABIC LETTER SAD WITH TWO DOTS BELOW
+    ('\u{069D}', JoiningType::DualJoining),
+    // ARABIC LETTER SAD WITH THREE DOTS ABOVE
+    ('\u{069E}', JoiningType::DualJoining),
+    // ARABIC LETTER TAH WITH THREE DOTS ABOVE
+    ('\u{069F}', JoiningType::DualJoining),
+    // ARABIC LETTER AIN WITH THREE DOTS ABOVE
+    ('\u{06A0}', JoiningType::DualJoining),
+    // ARABIC LETTER DOTLESS FEH
+    ('\u{06A1}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH DOT MOVED BELOW
+    ('\u{06A2}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH DOT BELOW
+    ('\u{06A3}', JoiningType::DualJoining),
+    // ARABIC LETTER VEH
+    ('\u{06A4}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH THREE DOTS BELOW
+    ('\u{06A5}', JoiningType::DualJoining),
+    // ARABIC LETTER PEHEH
+    ('\u{06A6}', JoiningType::DualJoining),
+    // ARABIC LETTER QAF WITH DOT ABOVE
+    ('\u{06A7}', JoiningType::DualJoining),
+    // ARABIC LETTER QAF WITH THREE DOTS ABOVE
+    ('\u{06A8}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH
+    ('\u{06A9}', JoiningType::DualJoining),
+    // ARABIC LETTER SWASH KAF
+    ('\u{06AA}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF WITH RING
+    ('\u{06AB}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF WITH DOT ABOVE
+    ('\u{06AC}', JoiningType::DualJoining),
+    // ARABIC LETTER NG
+    ('\u{06AD}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF WITH THREE DOTS BELOW
+    ('\u{06AE}', JoiningType::DualJoining),
+    // ARABIC LETTER GAF
+    ('\u{06AF}', JoiningType::DualJoining),
+    // ARABIC LETTER GAF WITH RING
+    ('\u{06B0}', JoiningType::DualJoining),
+    // ARABIC LETTER NGOEH
+    ('\u{06B1}', JoiningType::DualJoining),
+    // ARABIC LETTER GAF WITH TWO DOTS BELOW
+    ('\u{06B2}', JoiningType::DualJoining),
+    // ARABIC LETTER GUEH
+    ('\u{06B3}', JoiningType::DualJoining),
+    // ARABIC LETTER GAF WITH THREE DOTS ABOVE
+    ('\u{06B4}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH SMALL V
+    ('\u{06B5}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH DOT ABOVE
+    ('\u{06B6}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH THREE DOTS ABOVE
+    ('\u{06B7}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH THREE DOTS BELOW
+    ('\u{06B8}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH DOT BELOW
+    ('\u{06B9}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON GHUNNA
+    ('\u{06BA}', JoiningType::DualJoining),
+    // ARABIC LETTER RNOON
+    ('\u{06BB}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH RING
+    ('\u{06BC}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH THREE DOTS ABOVE
+    ('\u{06BD}', JoiningType::DualJoining),
+    // ARABIC LETTER HEH DOACHASHMEE
+    ('\u{06BE}', JoiningType::DualJoining),
+    // ARABIC LETTER TCHEH WITH DOT ABOVE
+    ('\u{06BF}', JoiningType::DualJoining),
+    // ARABIC LETTER HEH WITH YEH ABOVE
+    ('\u{06C0}', JoiningType::RightJoining),
+    // ARABIC LETTER HEH GOAL
+    ('\u{06C1}', JoiningType::DualJoining),
+    // ARABIC LETTER HEH GOAL WITH HAMZA ABOVE
+    ('\u{06C2}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH MARBUTA GOAL
+    ('\u{06C3}', JoiningType::RightJoining),
+    // ARABIC LETTER WAW WITH RING
+    ('\u{06C4}', JoiningType::RightJoining),
+    // ARABIC LETTER KIRGHIZ OE
+    ('\u{06C5}', JoiningType::RightJoining),
+    // ARABIC LETTER OE
+    ('\u{06C6}', JoiningType::RightJoining),
+    // ARABIC LETTER U
+    ('\u{06C7}', JoiningType::RightJoining),
+    // ARABIC LETTER YU
+    ('\u{06C8}', JoiningType::RightJoining),
+    // ARABIC LETTER KIRGHIZ YU
+    ('\u{06C9}', JoiningType::RightJoining),
+    // ARABIC LETTER WAW WITH TWO DOTS ABOVE
+    ('\u{06CA}', JoiningType::RightJoining),
+    // ARABIC LETTER VE
+    ('\u{06CB}', JoiningType::RightJoining),
+    // ARABIC LETTER FARSI YEH
+    ('\u{06CC}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH WITH TAIL
+    ('\u{06CD}', JoiningType::RightJoining),
+    // ARABIC LETTER YEH WITH SMALL V
+    ('\u{06CE}', JoiningType::DualJoining),
+    // ARABIC LETTER WAW WITH DOT ABOVE
+    ('\u{06CF}', JoiningType::RightJoining),
+    // ARABIC LETTER E
+    ('\u{06D0}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH WITH THREE DOTS BELOW
+    ('\u{06D1}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH BARREE
+    ('\u{06D2}', JoiningType::RightJoining),
+    // ARABIC LETTER YEH BARREE WITH HAMZA ABOVE
+    ('\u{06D3}', JoiningType::RightJoining),
+    // ARABIC LETTER AE
+    ('\u{06D5}', JoiningType::RightJoining),
+    // ARABIC END OF AYAH
+    ('\u{06DD}', JoiningType::NonJoining),
+    // ARABIC LETTER DAL WITH INVERTED V
+    ('\u{06EE}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH INVERTED V
+    ('\u{06EF}', JoiningType::RightJoining),
+    // ARABIC LETTER SHEEN WITH DOT BELOW
+    ('\u{06FA}', JoiningType::DualJoining),
+    // ARABIC LETTER DAD WITH DOT BELOW
+    ('\u{06FB}', JoiningType::DualJoining),
+    // ARABIC LETTER GHAIN WITH DOT BELOW
+    ('\u{06FC}', JoiningType::DualJoining),
+    // ARABIC LETTER HEH WITH INVERTED V
+    ('\u{06FF}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH THREE DOTS HORIZONTALLY BELOW
+    ('\u{0750}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH DOT BELOW AND THREE DOTS ABOVE
+    ('\u{0751}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH THREE DOTS POINTING UPWARDS BELOW
+    ('\u{0752}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH THREE DOTS POINTING UPWARDS BELOW AND TWO DOTS ABOVE
+    ('\u{0753}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH TWO DOTS BELOW AND DOT ABOVE
+    ('\u{0754}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH INVERTED SMALL V BELOW
+    ('\u{0755}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH SMALL V
+    ('\u{0756}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH TWO DOTS ABOVE
+    ('\u{0757}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH THREE DOTS POINTING UPWARDS BELOW
+    ('\u{0758}', JoiningType::DualJoining),
+    // ARABIC LETTER DAL WITH TWO DOTS VERTICALLY BELOW AND SMALL TAH
+    ('\u{0759}', JoiningType::RightJoining),
+    // ARABIC LETTER DAL WITH INVERTED SMALL V BELOW
+    ('\u{075A}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH STROKE
+    ('\u{075B}', JoiningType::RightJoining),
+    // ARABIC LETTER SEEN WITH FOUR DOTS ABOVE
+    ('\u{075C}', JoiningType::DualJoining),
+    // ARABIC LETTER AIN WITH TWO DOTS ABOVE
+    ('\u{075D}', JoiningType::DualJoining),
+    // ARABIC LETTER AIN WITH THREE DOTS POINTING DOWNWARDS ABOVE
+    ('\u{075E}', JoiningType::DualJoining),
+    // ARABIC LETTER AIN WITH TWO DOTS VERTICALLY ABOVE
+    ('\u{075F}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH TWO DOTS BELOW
+    ('\u{0760}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH THREE DOTS POINTING UPWARDS BELOW
+    ('\u{0761}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH DOT ABOVE
+    ('\u{0762}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH THREE DOTS ABOVE
+    ('\u{0763}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH THREE DOTS POINTING UPWARDS BELOW
+    ('\u{0764}', JoiningType::DualJoining),
+    // ARABIC LETTER MEEM WITH DOT ABOVE
+    ('\u{0765}', JoiningType::DualJoining),
+    // ARABIC LETTER MEEM WITH DOT BELOW
+    ('\u{0766}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH TWO DOTS BELOW
+    ('\u{0767}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH SMALL TAH
+    ('\u{0768}', JoiningType::DualJoining),
+    // ARABIC LETTER NOON WITH SMALL V
+    ('\u{0769}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH BAR
+    ('\u{076A}', JoiningType::DualJoining),
+    // ARABIC LETTER REH WITH TWO DOTS VERTICALLY ABOVE
+    ('\u{076B}', JoiningType::RightJoining),
+    // ARABIC LETTER REH WITH HAMZA ABOVE
+    ('\u{076C}', JoiningType::RightJoining),
+    // ARABIC LETTER SEEN WITH TWO DOTS VERTICALLY ABOVE
+    ('\u{076D}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH BELOW
+    ('\u{076E}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH AND TWO DOTS
+    ('\u{076F}', JoiningType::DualJoining),
+    // ARABIC LETTER SEEN WITH SMALL ARABIC LETTER TAH AND TWO DOTS
+    ('\u{0770}', JoiningType::DualJoining),
+    // ARABIC LETTER REH WITH SMALL ARABIC LETTER TAH AND TWO DOTS
+    ('\u{0771}', JoiningType::RightJoining),
+    // ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH ABOVE
+    ('\u{0772}', JoiningType::DualJoining),
+    // ARABIC LETTER ALEF WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE
+    ('\u{0773}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE
+    ('\u{0774}', JoiningType::RightJoining),
+    // ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE
+    ('\u{0775}', JoiningType::DualJoining),
+    // ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE
+    ('\u{0776}', JoiningType::DualJoining),
+    // ARABIC LETTER FARSI YEH WITH EXTENDED ARABIC-INDIC DIGIT FOUR BELOW
+    ('\u{0777}', JoiningType::DualJoining),
+    // ARABIC LETTER WAW WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE
+    ('\u{0778}', JoiningType::RightJoining),
+    // ARABIC LETTER WAW WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE
+    ('\u{0779}', JoiningType::RightJoining),
+    // ARABIC LETTER YEH BARREE WITH EXTENDED ARABIC-INDIC DIGIT TWO ABOVE
+    ('\u{077A}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH BARREE WITH EXTENDED ARABIC-INDIC DIGIT THREE ABOVE
+    ('\u{077B}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH EXTENDED ARABIC-INDIC DIGIT FOUR BELOW
+    ('\u{077C}', JoiningType::DualJoining),
+    // ARABIC LETTER SEEN WITH EXTENDED ARABIC-INDIC DIGIT FOUR ABOVE
+    ('\u{077D}', JoiningType::DualJoining),
+    // ARABIC LETTER SEEN WITH INVERTED V
+    ('\u{077E}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF WITH TWO DOTS ABOVE
+    ('\u{077F}', JoiningType::DualJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED FATHA
+    ('\u{0870}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED TOP RIGHT FATHA
+    ('\u{0871}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH RIGHT MIDDLE STROKE
+    ('\u{0872}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH LEFT MIDDLE STROKE
+    ('\u{0873}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED KASRA
+    ('\u{0874}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED BOTTOM RIGHT KASRA
+    ('\u{0875}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED ROUND DOT ABOVE
+    ('\u{0876}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED RIGHT ROUND DOT
+    ('\u{0877}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED LEFT ROUND DOT
+    ('\u{0878}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED ROUND DOT BELOW
+    ('\u{0879}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH DOT ABOVE
+    ('\u{087A}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED TOP RIGHT FATHA AND DOT ABOVE
+    ('\u{087B}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH RIGHT MIDDLE STROKE AND DOT ABOVE
+    ('\u{087C}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED BOTTOM RIGHT KASRA AND DOT ABOVE
+    ('\u{087D}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED TOP RIGHT FATHA AND LEFT RING
+    ('\u{087E}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH RIGHT MIDDLE STROKE AND LEFT RING
+    ('\u{087F}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED BOTTOM RIGHT KASRA AND LEFT RING
+    ('\u{0880}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED RIGHT HAMZA
+    ('\u{0881}', JoiningType::RightJoining),
+    // ARABIC LETTER ALEF WITH ATTACHED LEFT HAMZA
+    ('\u{0882}', JoiningType::RightJoining),
+    // ARABIC TATWEEL WITH OVERSTRUCK HAMZA
+    ('\u{0883}', JoiningType::JoinCausing),
+    // ARABIC TATWEEL WITH OVERSTRUCK WAW
+    ('\u{0884}', JoiningType::JoinCausing),
+    // ARABIC TATWEEL WITH TWO DOTS BELOW
+    ('\u{0885}', JoiningType::JoinCausing),
+    // ARABIC LETTER THIN YEH
+    ('\u{0886}', JoiningType::DualJoining),
+    // ARABIC BASELINE ROUND DOT
+    ('\u{0887}', JoiningType::NonJoining),
+    // ARABIC LETTER NOON WITH INVERTED SMALL V
+    ('\u{0889}', JoiningType::DualJoining),
+    // ARABIC LETTER HAH WITH INVERTED SMALL V BELOW
+    ('\u{088A}', JoiningType::DualJoining),
+    // ARABIC LETTER TAH WITH DOT BELOW
+    ('\u{088B}', JoiningType::DualJoining),
+    // ARABIC LETTER TAH WITH THREE DOTS BELOW
+    ('\u{088C}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH TWO DOTS VERTICALLY BELOW
+    ('\u{088D}', JoiningType::DualJoining),
+    // ARABIC VERTICAL TAIL
+    ('\u{088E}', JoiningType::RightJoining),
+    // ARABIC POUND MARK ABOVE
+    ('\u{0890}', JoiningType::NonJoining),
+    // ARABIC PIASTRE MARK ABOVE
+    ('\u{0891}', JoiningType::NonJoining),
+    // ARABIC LETTER BEH WITH SMALL V BELOW
+    ('\u{08A0}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH HAMZA ABOVE
+    ('\u{08A1}', JoiningType::DualJoining),
+    // ARABIC LETTER JEEM WITH TWO DOTS ABOVE
+    ('\u{08A2}', JoiningType::DualJoining),
+    // ARABIC LETTER TAH WITH TWO DOTS ABOVE
+    ('\u{08A3}', JoiningType::DualJoining),
+    // ARABIC LETTER FEH WITH DOT BELOW AND THREE DOTS ABOVE
+    ('\u{08A4}', JoiningType::DualJoining),
+    // ARABIC LETTER QAF WITH DOT BELOW
+    ('\u{08A5}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH DOUBLE BAR
+    ('\u{08A6}', JoiningType::DualJoining),
+    // ARABIC LETTER MEEM WITH THREE DOTS ABOVE
+    ('\u{08A7}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH WITH TWO DOTS BELOW AND HAMZA ABOVE
+    ('\u{08A8}', JoiningType::DualJoining),
+    // ARABIC LETTER YEH WITH TWO DOTS BELOW AND DOT ABOVE
+    ('\u{08A9}', JoiningType::DualJoining),
+    // ARABIC LETTER REH WITH LOOP
+    ('\u{08AA}', JoiningType::RightJoining),
+    // ARABIC LETTER WAW WITH DOT WITHIN
+    ('\u{08AB}', JoiningType::RightJoining),
+    // ARABIC LETTER ROHINGYA YEH
+    ('\u{08AC}', JoiningType::RightJoining),
+    // ARABIC LETTER LOW ALEF
+    ('\u{08AD}', JoiningType::NonJoining),
+    // ARABIC LETTER DAL WITH THREE DOTS BELOW
+    ('\u{08AE}', JoiningType::RightJoining),
+    // ARABIC LETTER SAD WITH THREE DOTS BELOW
+    ('\u{08AF}', JoiningType::DualJoining),
+    // ARABIC LETTER GAF WITH INVERTED STROKE
+    ('\u{08B0}', JoiningType::DualJoining),
+    // ARABIC LETTER STRAIGHT WAW
+    ('\u{08B1}', JoiningType::RightJoining),
+    // ARABIC LETTER ZAIN WITH INVERTED V ABOVE
+    ('\u{08B2}', JoiningType::RightJoining),
+    // ARABIC LETTER AIN WITH THREE DOTS BELOW
+    ('\u{08B3}', JoiningType::DualJoining),
+    // ARABIC LETTER KAF WITH DOT BELOW
+    ('\u{08B4}', JoiningType::DualJoining),
+    // ARABIC LETTER QAF WITH DOT BELOW AND NO DOTS ABOVE
+    ('\u{08B5}', JoiningType::DualJoining),
+    // ARABIC LETTER BEH WITH SMALL MEEM ABOVE
+    ('\u{08B6}', JoiningType::DualJoining),
+    // ARABIC LETTER PEH WITH SMALL MEEM ABOVE
+    ('\u{08B7}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH WITH SMALL TEH ABOVE
+    ('\u{08B8}', JoiningType::DualJoining),
+    // ARABIC LETTER REH WITH SMALL NOON ABOVE
+    ('\u{08B9}', JoiningType::RightJoining),
+    // ARABIC LETTER YEH WITH TWO DOTS BELOW AND SMALL NOON ABOVE
+    ('\u{08BA}', JoiningType::DualJoining),
+    // ARABIC LETTER AFRICAN FEH
+    ('\u{08BB}', JoiningType::DualJoining),
+    // ARABIC LETTER AFRICAN QAF
+    ('\u{08BC}', JoiningType::DualJoining),
+    // ARABIC LETTER AFRICAN NOON
+    ('\u{08BD}', JoiningType::DualJoining),
+    // ARABIC LETTER PEH WITH SMALL V
+    ('\u{08BE}', JoiningType::DualJoining),
+    // ARABIC LETTER TEH WITH SMALL V
+    ('\u{08BF}', JoiningType::DualJoining),
+    // ARABIC LETTER TTEH WITH SMALL V
+    ('\u{08C0}', JoiningType::DualJoining),
+    // ARABIC LETTER TCHEH WITH SMALL V
+    ('\u{08C1}', JoiningType::DualJoining),
+    // ARABIC LETTER KEHEH WITH SMALL V
+    ('\u{08C2}', JoiningType::DualJoining),
+    // ARABIC LETTER GHAIN WITH THREE DOTS ABOVE
+    ('\u{08C3}', JoiningType::DualJoining),
+    // ARABIC LETTER AFRICAN QAF WITH THREE DOTS ABOVE
+    ('\u{08C4}', JoiningType::DualJoining),
+    // ARABIC LETTER JEEM WITH THREE DOTS ABOVE
+    ('\u{08C5}', JoiningType::DualJoining),
+    // ARABIC LETTER JEEM WITH THREE DOTS BELOW
+    ('\u{08C6}', JoiningType::DualJoining),
+    // ARABIC LETTER LAM WITH SMALL ARABIC LETTER TAH ABOVE
+    ('\u{08C7}', JoiningType::DualJoining),
+    // ARABIC LETTER GRAF
+    ('\u{08C8}', JoiningType::DualJoining),
+    // ARABIC DISPUTED END OF AYAH
+    ('\u{08E2}', JoiningType::NonJoining),
+];
+
+/// Presentation forms of the letters that have them
+pub const UNICODE_FORMS: [LettersType; 76] = [
+    // ARABIC LETTER HAMZA
+    ('\u{0621}', Forms::new('\u{FE80}', '\0', '\0', '\0')),
+    // ARABIC LETTER ALEF WITH MADDA ABOVE
+    ('\u{0622}', Forms::new('\u{FE81}', '\0', '\0', '\u{FE82}')),
+    // ARABIC LETTER ALEF WITH HAMZA ABOVE
+    ('\u{0623}', Forms::new('\u{FE83}', '\0', '\0', '\u{FE84}')),
+    // ARABIC LETTER WAW WITH HAMZA ABOVE
+    ('\u{0624}', Forms::new('\u{FE85}', '\0', '\0', '\u{FE86}')),
+    // ARABIC LETTER ALEF WITH HAMZA BELOW
+    ('\u{0625}', Forms::new('\u{FE87}', '\0', '\0', '\u{FE88}')),
+    // ARABIC LETTER YEH WITH HAMZA ABOVE
+    (
+        '\u{0626}',
+        Forms::new('\u{FE89}', '\u{FE8B}', '\u{FE8C}', '\u{FE8A}'),
+    ),
+    // ARABIC LETTER ALEF
+    ('\u{0627}', Forms::new('\u{FE8D}', '\0', '\0', '\u{FE8E}')),
+    // ARABIC LETTER BEH
+    (
+        '\u{0628}',
+        Forms::new('\u{FE8F}', '\u{FE91}', '\u{FE92}', '\u{FE90}'),
+    ),
+    // ARABIC LETTER TEH MARBUTA
+    ('\u{0629}', Forms::new('\u{FE93}', '\0', '\0', '\u{FE94}')),
+    // ARABIC LETTER TEH
+    (
+        '\u{062A}',
+        Forms::new('\u{FE95}', '\u{FE97}', '\u{FE98}', '\u{FE96}'),
+    ),
+    // ARABIC LETTER THEH
+    (
+        '\u{062B}',
+        Forms::new('\u{FE99}', '\u{FE9B}', '\u{FE9C}', '\u{FE9A}'),
+    ),
+    // ARABIC LETTER JEEM
+    (
+        '\u{062C}',
+        Forms::new('\u{FE9D}', '\u{FE9F}', '\u{FEA0}', '\u{FE9E}'),
+    ),
+    // ARABIC LETTER HAH
+    (
+        '\u{062D}',
+        Forms::new('\u{FEA1}', '\u{FEA3}', '\u{FEA4}', '\u{FEA2}'),
+    ),
+    // ARABIC LETTER KHAH
+    (
+        '\u{062E}',
+        Forms::new('\u{FEA5}', '\u{FEA7}', '\u{FEA8}', '\u{FEA6}'),
+    ),
+    // ARABIC LETTER DAL
+    ('\u{062F}', Forms::new('\u{FEA9}', '\0', '\0', '\u{FEAA}')),
+    // ARABIC LETTER THAL
+    ('\u{0630}', Forms::new('\u{FEAB}', '\0', '\0', '\u{FEAC}')),
+    // ARABIC LETTER REH
+    ('\u{0631}', Forms::new('\u{FEAD}', '\0', '\0', '\u{FEAE}')),
+    // ARABIC LETTER ZAIN
+    ('\u{0632}', Forms::new('\u{FEAF}', '\0', '\0', '\u{FEB0}')),
+    // ARABIC LETTER SEEN
+    (
+        '\u{0633}',
+        Forms::new('\u{FEB1}', '\u{FEB3}', '\u{FEB4}', '\u{FEB2}'),
+    ),
+    // ARABIC LETTER SHEEN
+    (
+        '\u{0634}',
+        Forms::new('\u{FEB5}', '\u{FEB7}', '\u{FEB8}', '\u{FEB6}'),
+    ),
+    // ARABIC LETTER SAD
+    (
+        '\u{0635}',
+        Forms::new('\u{FEB9}', '\u{FEBB}', '\u{FEBC}', '\u{FEBA}'),
+    ),
+    // ARABIC LETTER DAD
+    (
+        '\u{0636}',
+        Forms::new('\u{FEBD}', '\u{FEBF}', '\u{FEC0}', '\u{FEBE}'),
+    ),
+    // ARABIC LETTER TAH
+    (
+        '\u{0637}',
+        Forms::new('\u{FEC1}', '\u{FEC3}', '\u{FEC4}', '\u{FEC2}'),
+    ),
+    // ARABIC LETTER ZAH
+    (
+        '\u{0638}',
+        Forms::new('\u{FEC5}', '\u{FEC7}', '\u{FEC8}', '\u{FEC6}'),
+    ),
+    // ARABIC LETTER AIN
+    (
+        '\u{0639}',
+        Forms::new('\u{FEC9}', '\u{FECB}', '\u{FECC}', '\u{FECA}'),
+    ),
+    // ARABIC LETTER GHAIN
+    (
+        '\u{063A}',
+        Forms::new('\u{FECD}', '\u{FECF}', '\u{FED0}', '\u{FECE}'),
+    ),
+    // ARABIC LETTER FEH
+    (
+        '\u{0641}',
+        Forms::new('\u{FED1}', '\u{FED3}', '\u{FED4}', '\u{FED2}'),
+    ),
+    // ARABIC LETTER QAF
+    (
+        '\u{0642}',
+        Forms::new('\u{FED5}', '\u{FED7}', '\u{FED8}', '\u{FED6}'),
+    ),
+    // ARABIC LETTER KAF
+    (
+        '\u{0643}',
+        Forms::new('\u{FED9}', '\u{FEDB}', '\u{FEDC}', '\u{FEDA}'),
+    ),
+    // ARABIC LETTER LAM
+    (
+        '\u{0644}',
+        Forms::new('\u{FEDD}', '\u{FEDF}', '\u{FEE0}', '\u{FEDE}'),
+    ),
+    // ARABIC LETTER MEEM
+    (
+        '\u{0645}',
+        Forms::new('\u{FEE1}', '\u{FEE3}', '\u{FEE4}', '\u{FEE2}'),
+    ),
+    // ARABIC LETTER NOON
+    (
+        '\u{0646}',
+        Forms::new('\u{FEE5}', '\u{FEE7}', '\u{FEE8}', '\u{FEE6}'),
+    ),
+    // ARABIC LETTER HEH
+    (
+        '\u{0647}',
+        Forms::new('\u{FEE9}', '\u{FEEB}', '\u{FEEC}', '\u{FEEA}'),
+    ),
+    // ARABIC LETTER WAW
+    ('\u{0648}', Forms::new('\u{FEED}', '\0', '\0', '\u{FEEE}')),
+    // ARABIC LETTER ALEF MAKSURA
+    (
+        '\u{0649}',
+        Forms::new('\u{FEEF}', '\u{FBE8}', '\u{FBE9}', '\u{FEF0}'),
+    ),
+    // ARABIC LETTER YEH
+    (
+        '\u{064A}',
+        Forms::new('\u{FEF1}', '\u{FEF3}', '\u{FEF4}', '\u{FEF2}'),
+    ),
+    // ARABIC LETTER ALEF WASLA
+    ('\u{0671}', Forms::new('\u{FB50}', '\0', '\0', '\u{FB51}')),
+    // ARABIC LETTER U WITH HAMZA ABOVE
+    ('\u{0677}', Forms::new('\u{FBDD}', '\0', '\0', '\0')),
+    // ARABIC LETTER TTEH
+    (
+        '\u{0679}',
+        Forms::new('\u{FB66}', '\u{FB68}', '\u{FB69}', '\u{FB67}'),
+    ),
+    // ARABIC LETTER TTEHEH
+    (
+        '\u{067A}',
+        Forms::new('\u{FB5E}', '\u{FB60}', '\u{FB61}', '\u{FB5F}'),
+    ),
+    // ARABIC LETTER BEEH
+    (
+        '\u{067B}',
+        Forms::new('\u{FB52}', '\u{FB54}', '\u{FB55}', '\u{FB53}'),
+    ),
+    // ARABIC LETTER PEH
+    (
+        '\u{067E}',
+        Forms::new('\u{FB56}', '\u{FB58}', '\u{FB59}', '\u{FB57}'),
+    ),
+    // ARABIC LETTER TEHEH
+    (
+        '\u{067F}',
+        Forms::new('\u{FB62}', '\u{FB64}', '\u{FB65}', '\u{FB63}'),
+    ),
+    // ARABIC LETTER BEHEH
+    (
+        '\u{0680}',
+        Forms::new('\u{FB5A}', '\u{FB5C}', '\u{FB5D}', '\u{FB5B}'),
+    ),
+    // ARABIC LETTER NYEH
+    (
+        '\u{0683}',
+        Forms::new('\u{FB76}', '\u{FB78}', '\u{FB79}', '\u{FB77}'),
+    ),
+    // ARABIC LETTER DYEH
+    (
+        '\u{0684}',
+        Forms::new('\u{FB72}', '\u{FB74}', '\u{FB75}', '\u{FB73}'),
+    ),
+    // ARABIC LETTER TCHEH
+    (
+        '\u{0686}',
+        Forms::new('\u{FB7A}', '\u{FB7C}', '\u{FB7D}', '\u{FB7B}'),
+    ),
+    // ARABIC LETTER TCHEHEH
+    (
+        '\u{0687}',
+        Forms::new('\u{FB7E}', '\u{FB80}', '\u{FB81}', '\u{FB7F}'),
+    ),
+    // ARABIC LETTER DDAL
+    ('\u{0688}', Forms::new('\u{FB88}', '\0', '\0', '\u{FB89}')),
+    // ARABIC LETTER DAHAL
+    ('\u{068C}', Forms::new('\u{FB84}', '\0', '\0', '\u{FB85}')),
+    // ARABIC LETTER DDAHAL
+    ('\u{068D}', Forms::new('\u{FB82}', '\0', '\0', '\u{FB83}')),
+    // ARABIC LETTER DUL
+    ('\u{068E}', Forms::new('\u{FB86}', '\0', '\0', '\u{FB87}')),
+    // ARABIC LETTER RREH
+    ('\u{0691}', Forms::new('\u{FB8C}', '\0', '\0', '\u{FB8D}')),
+    // ARABIC LETTER JEH
+    ('\u{0698}', Forms::new('\u{FB8A}', '\0', '\0', '\u{FB8B}')),
+    // ARABIC LETTER VEH
+    (
+        '\u{06A4}',
+        Forms::new('\u{FB6A}', '\u{FB6C}', '\u{FB6D}', '\u{FB6B}'),
+    ),
+    // ARABIC LETTER PEHEH
+    (
+        '\u{06A6}',
+        Forms::new('\u{FB6E}', '\u{FB70}', '\u{FB71}', '\u{FB6F}'),
+    ),
+    // ARABIC LETTER KEHEH
+    (
+        '\u{06A9}',
+        Forms::new('\u{FB8E}', '\u{FB90}', '\u{FB91}', '\u{FB8F}'),
+    ),
+    // ARABIC LETTER NG
+    (
+        '\u{06AD}',
+        Forms::new('\u{FBD3}', '\u{FBD5}', '\u{FBD6}', '\u{FBD4}'),
+    ),
+    // ARABIC LETTER GAF
+    (
+        '\u{06AF}',
+        Forms::new('\u{FB92}', '\u{FB94}', '\u{FB95}', '\u{FB93}'),
+    ),
+    // ARABIC LETTER NGOEH
+    (
+        '\u{06B1}',
+        Forms::new('\u{FB9A}', '\u{FB9C}', '\u{FB9D}', '\u{FB9B}'),
+    ),
+    // ARABIC LETTER GUEH
+    (
+        '\u{06B3}',
+        Forms::new('\u{FB96}', '\u{FB98}', '\u{FB99}', '\u{FB97}'),
+    ),
+    // ARABIC LETTER NOON GHUNNA
+    ('\u{06BA}', Forms::new('\u{FB9E}', '\0', '\0', '\u{FB9F}')),
+    // ARABIC LETTER RNOON
+    (
+        '\u{06BB}',
+        Forms::new('\u{FBA0}', '\u{FBA2}', '\u{FBA3}', '\u{FBA1}'),
+    ),
+    // ARABIC LETTER HEH DOACHASHMEE
+    (
+        '\u{06BE}',
+        Forms::new('\u{FBAA}', '\u{FBAC}', '\u{FBAD}', '\u{FBAB}'),
+    ),
+    // ARABIC LETTER HEH WITH YEH ABOVE
+    ('\u{06C0}', Forms::new('\u{FBA4}', '\0', '\0', '\u{FBA5}')),
+    // ARABIC LETTER HEH GOAL
+    (
+        '\u{06C1}',
+        Forms::new('\u{FBA6}', '\u{FBA8}', '\u{FBA9}', '\u{FBA7}'),
+    ),
+    // ARABIC LETTER KIRGHIZ OE
+    ('\u{06C5}', Forms::new('\u{FBE0}', '\0', '\0', '\u{FBE1}')),
+    // ARABIC LETTER OE
+    ('\u{06C6}', Forms::new('\u{FBD9}', '\0', '\0', '\u{FBDA}')),
+    // ARABIC LETTER U
+    ('\u{06C7}', Forms::new('\u{FBD7}', '\0', '\0', '\u{FBD8}')),
+    // ARABIC LETTER YU
+    ('\u{06C8}', Forms::new('\u{FBDB}', '\0', '\0', '\u{FBDC}')),
+    // ARABIC LETTER KIRGHIZ YU
+    ('\u{06C9}', Forms::new('\u{FBE2}', '\0', '\0', '\u{FBE3}')),
+    // ARABIC LETTER VE
+    ('\u{06CB}', Forms::new('\u{FBDE}', '\0', '\0', '\u{FBDF}')),
+    // ARABIC LETTER FARSI YEH
+    (
+        '\u{06CC}',
+        Forms::new('\u{FBFC}', '\u{FBFE}', '\u{FBFF}', '\u{FBFD}'),
+    ),
+    // ARABIC LETTER E
+    (
+        '\u{06D0}',
+        Forms::new('\u{FBE4}', '\u{FBE6}', '\u{FBE7}', '\u{FBE5}'),
+    ),
+    // ARABIC LETTER YEH BARREE
+    ('\u{06D2}', Forms::new('\u{FBAE}', '\0', '\0', '\u{FBAF}')),
+    // ARABIC LETTER YEH BARREE WITH HAMZA ABOVE
+    ('\u{06D3}', Forms::new('\u{FBB0}', '\0', '\0', '\u{FBB1}')),
+];
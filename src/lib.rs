@@ -5,6 +5,7 @@
 //! ## Usage:
 //! reshape a single string
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use ar_reshaper::{ArabicReshaper, reshape_line};
 //!
 //! let reshaper = ArabicReshaper::default();
@@ -15,30 +16,47 @@
 //! // and you just want to reshape a line with default settings
 //! println!("{}", reshape_line("سلام دنیا"));
 //! // Both will reconstruct the string and print `ﺳﻼﻡ ﺩﻧﯿﺎ`
+//! # }
 //! ```
 //!
 //! reshape a slice of strings
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use ar_reshaper::ArabicReshaper;
 //!
 //! let reshaper = ArabicReshaper::default();
 //!
 //! println!("{:#?}", reshaper.reshape_lines(["سلام خوبی؟", "عالیم ممنون"]));
 //! // this will reconstruct the string and print  ["ﺳﻼﻡ ﺧﻮﺑﯽ؟", "ﻋﺎﻟﯿﻢ ﻣﻤﻨﻮﻥ"]
+//! # }
 //! ```
 //!
 //! You can also reshape strings on a iterator
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use ar_reshaper::prelude::*;
 //!
 //! for line in ["یک", "دو"].iter().reshape_default() {
 //!     println!("{line}");
 //! }
+//! # }
+//! ```
+//!
+//! Or reshape the characters as they come, without waiting for the whole line
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use ar_reshaper::prelude::*;
+//!
+//! for c in "سلام دنیا".chars().reshape_chars_default() {
+//!     print!("{c}");
+//! }
+//! # }
 //! ```
 //!
 //! You can also check if a text need reshaping or not, this method can be
 //! useful when you dont want a copy of original string in case of no reshape.
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use ar_reshaper::ArabicReshaper;
 //!
 //! let reshaper = ArabicReshaper::default();
@@ -47,6 +65,19 @@
 //! if reshaper.need_reshape(text) {
 //!     println!("{}", reshaper.reshape(text));
 //! }
+//! # }
+//! ```
+//!
+//! Or let [`reshape_cow`](ArabicReshaper::reshape_cow) do it for you, the text
+//! is borrowed when there is nothing to reshape in it.
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use ar_reshaper::ArabicReshaper;
+//!
+//! let reshaper = ArabicReshaper::default();
+//!
+//! println!("{}", reshaper.reshape_cow("من به reshape نیاز دارم"));
+//! # }
 //! ```
 //!
 //! A rusty rewrite of [python-arabic-reshaper](https://github.com/mpcabd/python-arabic-reshaper)
@@ -55,31 +86,62 @@
 #![forbid(unsafe_code)]
 #![warn(missing_copy_implementations)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "alloc")]
 use alloc::string::String;
 
 pub use config::{Language, ReshaperConfig};
+pub use error::ReshapeError;
 pub use reshaper::ArabicReshaper;
+#[cfg(feature = "alloc")]
+pub use reshaper::ReshapeBuffer;
 
+#[cfg(feature = "bidi")]
+pub mod bidi;
+#[cfg(feature = "catalogs")]
+pub mod catalogs;
 pub mod config;
+pub mod error;
+#[cfg(feature = "ttf-parser")]
+pub mod font;
 pub mod form;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "alloc")]
 pub mod iterator;
+#[cfg(feature = "alloc")]
+pub mod justify;
+#[cfg(feature = "layout")]
+pub mod layout;
 pub mod letters;
 mod ligatures;
+#[cfg(feature = "alloc")]
+pub mod markup;
 mod reshaper;
+mod segment;
+#[cfg(feature = "subtitles")]
+pub mod subtitles;
 
 pub mod prelude {
     pub use crate::config::*;
+    pub use crate::error::ReshapeError;
+    #[cfg(feature = "alloc")]
     pub use crate::iterator::*;
     pub use crate::ligatures::LigatureNames;
     pub use crate::reshaper::ArabicReshaper;
+    #[cfg(feature = "alloc")]
+    pub use crate::reshaper::ReshapeBuffer;
 }
 
 /// Reshape the given text with the default [ArabicReshaper] configuration.\
 /// Keep in mind that if you want to reshape a large amount of lines its better
 /// to first create a [ArabicReshaper] and then use the `reshape` or `reshape_lines`
 /// methods of it instead.
+#[cfg(feature = "alloc")]
 pub fn reshape_line<S>(text: S) -> String
 where
     S: AsRef<str>,
@@ -0,0 +1,459 @@
+//! Multi-pattern matcher for the ligatures.
+//!
+//! An Aho-Corasick automaton that is built at compile time from the [`LIGATURES`](super::LIGATURES)
+//! table, so all the ligatures can be found in a single pass over the text.
+
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+
+use super::LigaturePattern;
+use crate::form::Forms;
+
+/// Used for links that point to nothing, the root can't be the target of them.
+const NONE: u16 = 0;
+/// State of the automaton before seeing any character
+pub(crate) const ROOT: u16 = 0;
+
+/// First character covered by the direct-indexed children of the root
+const BLOCK_START: u32 = 0x0600;
+/// Number of characters covered by the direct-indexed children of the root (U+0600 - U+06FF)
+const BLOCK_LEN: usize = 0x100;
+
+#[derive(Clone, Copy)]
+struct Node {
+    /// The character on the edge coming from the parent
+    label: char,
+    /// First child of the node, children of a node are next to each other and sorted by label
+    first_child: u16,
+    /// Number of children of the node
+    children: u16,
+    /// Longest proper suffix of this node that is also in the trie
+    fail: u16,
+    /// Nearest node in the fail chain that has outputs
+    output_link: u16,
+    /// Position + 1 of the first output of the node
+    output: u16,
+    /// Length of the node's string in characters
+    depth: u16,
+}
+
+impl Node {
+    const EMPTY: Self = Self {
+        label: '\0',
+        first_child: NONE,
+        children: 0,
+        fail: ROOT,
+        output_link: NONE,
+        output: NONE,
+        depth: 0,
+    };
+}
+
+/// Ligature matched in a node, nodes can have more then one output
+/// when some ligatures use the same string.
+#[derive(Clone, Copy)]
+struct Output {
+    ligature: u16,
+    /// Position + 1 of the next output of the same node
+    next: u16,
+}
+
+/// The ligatures that end in a state of the automaton
+#[derive(Clone, Copy)]
+pub(crate) struct Matches {
+    /// Node that has the next output, [`NONE`] when there is no more output
+    node: u16,
+    /// Position + 1 of the next output
+    output: u16,
+}
+
+/// Number of strings and characters that the pattern expand to, starting from `pos`.
+const fn pattern_size(bytes: &[u8], pos: usize) -> (usize, usize) {
+    if pos == bytes.len() {
+        return (1, 0);
+    }
+
+    let (alternatives, next) = next_position(bytes, pos);
+    let (strings, chars) = pattern_size(bytes, next);
+    (alternatives * strings, alternatives * (chars + strings))
+}
+
+/// Number of strings and characters in the given ligatures,
+/// used to find the size of the [`Automaton`].
+const fn ligatures_size(ligatures: &[(LigaturePattern, Forms)]) -> (usize, usize) {
+    let (mut strings, mut chars) = (0, 0);
+
+    let mut idx = 0;
+    while idx < ligatures.len() {
+        let words = ligatures[idx].0.matches;
+        let mut w = 0;
+        while w < words.len() {
+            let size = pattern_size(words[w].as_bytes(), 0);
+            strings += size.0;
+            chars += size.1;
+            w += 1;
+        }
+        idx += 1;
+    }
+
+    (strings, chars)
+}
+
+/// Maximum number of nodes needed for the given ligatures.
+pub(crate) const fn nodes_len(ligatures: &[(LigaturePattern, Forms)]) -> usize {
+    ligatures_size(ligatures).1 + 1
+}
+
+/// Number of outputs needed for the given ligatures.
+pub(crate) const fn outputs_len(ligatures: &[(LigaturePattern, Forms)]) -> usize {
+    ligatures_size(ligatures).0
+}
+
+/// Find the number of characters that are accepted in the position starting at `pos`,
+/// either a single character or the ones inside brackets, and the start of the next position.
+pub(crate) const fn next_position(bytes: &[u8], pos: usize) -> (usize, usize) {
+    if bytes[pos] != b'[' {
+        return (1, next_char(bytes, pos).1);
+    }
+
+    let mut alternatives = 0;
+    let mut pos = pos + 1;
+    while bytes[pos] != b']' {
+        pos = next_char(bytes, pos).1;
+        alternatives += 1;
+    }
+    (alternatives, pos + 1)
+}
+
+/// Decode the utf-8 character starting at `pos`, return it and the position of the next character.
+pub(crate) const fn next_char(bytes: &[u8], pos: usize) -> (char, usize) {
+    let first = bytes[pos] as u32;
+    let (mut code, len) = match first {
+        0x00..=0x7F => (first, 1),
+        0xC0..=0xDF => (first & 0x1F, 2),
+        0xE0..=0xEF => (first & 0x0F, 3),
+        _ => (first & 0x07, 4),
+    };
+
+    let mut idx = 1;
+    while idx < len {
+        code = (code << 6) | (bytes[pos + idx] as u32 & 0x3F);
+        idx += 1;
+    }
+
+    match char::from_u32(code) {
+        Some(c) => (c, pos + len),
+        None => panic!("Invalid utf-8 in ligatures"),
+    }
+}
+
+/// A simple trie that is used to build the [`Automaton`], children are kept in a linked list.
+struct Trie<const N: usize, const O: usize> {
+    labels: [char; N],
+    first_child: [u16; N],
+    sibling: [u16; N],
+    /// Position + 1 of the first output of each node
+    outputs: [u16; N],
+    len: usize,
+    entries: [Output; O],
+    entries_len: usize,
+}
+
+impl<const N: usize, const O: usize> Trie<N, O> {
+    /// Insert the pattern starting at `pos` under the given node.
+    const fn insert(&mut self, bytes: &[u8], pos: usize, node: u16, ligature: u16) {
+        if pos == bytes.len() {
+            self.add_output(node, ligature);
+            return;
+        }
+
+        let (alternatives, next) = next_position(bytes, pos);
+        // skip the bracket
+        let mut pos = if bytes[pos] == b'[' { pos + 1 } else { pos };
+
+        let mut idx = 0;
+        while idx < alternatives {
+            let (c, after) = next_char(bytes, pos);
+            pos = after;
+
+            let child = self.child_or_insert(node, c);
+            self.insert(bytes, next, child, ligature);
+            idx += 1;
+        }
+    }
+
+    const fn child_or_insert(&mut self, node: u16, c: char) -> u16 {
+        let mut child = self.first_child[node as usize];
+        while child != NONE && self.labels[child as usize] != c {
+            child = self.sibling[child as usize];
+        }
+
+        if child == NONE {
+            child = self.len as u16;
+            self.labels[self.len] = c;
+            self.sibling[self.len] = self.first_child[node as usize];
+            self.first_child[node as usize] = child;
+            self.len += 1;
+        }
+
+        child
+    }
+
+    /// Add the output to the end of the node outputs, so outputs stay
+    /// in the same order as the ligatures
+    const fn add_output(&mut self, node: u16, ligature: u16) {
+        self.entries[self.entries_len].ligature = ligature;
+        self.entries_len += 1;
+        let output = self.entries_len as u16;
+
+        let mut tail = self.outputs[node as usize];
+        if tail == NONE {
+            self.outputs[node as usize] = output;
+        } else {
+            while self.entries[tail as usize - 1].next != NONE {
+                tail = self.entries[tail as usize - 1].next;
+            }
+            self.entries[tail as usize - 1].next = output;
+        }
+    }
+}
+
+/// The ligatures automaton.
+///
+/// `N` is the maximum number of nodes and `O` is the number of outputs (strings) in the ligatures.
+pub(crate) struct Automaton<const N: usize, const O: usize> {
+    nodes: [Node; N],
+    outputs: [Output; O],
+    /// Children of the root for the characters in the Arabic block, almost every
+    /// character goes through the root so we don't want to search its children.
+    root: [u16; BLOCK_LEN],
+}
+
+impl<const N: usize, const O: usize> Automaton<N, O> {
+    /// Build the automaton from the given ligatures.
+    pub(crate) const fn new(ligatures: &[(LigaturePattern, Forms)]) -> Self {
+        let mut automaton = Self {
+            nodes: [Node::EMPTY; N],
+            outputs: [Output {
+                ligature: 0,
+                next: NONE,
+            }; O],
+            root: [NONE; BLOCK_LEN],
+        };
+
+        let mut trie = Trie {
+            labels: ['\0'; N],
+            first_child: [NONE; N],
+            sibling: [NONE; N],
+            outputs: [NONE; N],
+            len: 1,
+            entries: automaton.outputs,
+            entries_len: 0,
+        };
+
+        let mut idx = 0;
+        while idx < ligatures.len() {
+            let words = ligatures[idx].0.matches;
+            let mut w = 0;
+            while w < words.len() {
+                trie.insert(words[w].as_bytes(), 0, ROOT, idx as u16);
+                w += 1;
+            }
+            idx += 1;
+        }
+
+        let Trie {
+            labels,
+            first_child,
+            sibling,
+            outputs,
+            len,
+            entries,
+            ..
+        } = trie;
+        automaton.outputs = entries;
+
+        // Renumber the nodes in breadth first order, so the children of each node
+        // end up next to each other and can be sorted by their label
+        let mut order = [ROOT; N];
+        let (mut head, mut tail) = (0, 1);
+        while head < tail {
+            let old = order[head] as usize;
+
+            let start = tail;
+            let mut child = first_child[old];
+            while child != NONE {
+                // insertion sort by label
+                let mut pos = tail;
+                while pos > start
+                    && labels[order[pos - 1] as usize] as u32 > labels[child as usize] as u32
+                {
+                    order[pos] = order[pos - 1];
+                    pos -= 1;
+                }
+                order[pos] = child;
+                tail += 1;
+                child = sibling[child as usize];
+            }
+
+            automaton.nodes[head] = Node {
+                label: labels[old],
+                first_child: start as u16,
+                children: (tail - start) as u16,
+                output: outputs[old],
+                ..automaton.nodes[head]
+            };
+
+            let mut new = start;
+            while new < tail {
+                automaton.nodes[new].depth = automaton.nodes[head].depth + 1;
+                if head == ROOT as usize {
+                    let label = labels[order[new] as usize] as u32;
+                    if label.wrapping_sub(BLOCK_START) < BLOCK_LEN as u32 {
+                        automaton.root[(label - BLOCK_START) as usize] = new as u16;
+                    }
+                }
+                new += 1;
+            }
+
+            head += 1;
+        }
+
+        // Find the fail links, parents always come before their children
+        let mut node = 0;
+        while node < len {
+            let Node {
+                first_child,
+                children,
+                fail,
+                ..
+            } = automaton.nodes[node];
+
+            let mut child = first_child as usize;
+            while child < (first_child + children) as usize {
+                let fail = if node == ROOT as usize {
+                    ROOT
+                } else {
+                    automaton.next_state(fail, automaton.nodes[child].label)
+                };
+                automaton.nodes[child].fail = fail;
+                automaton.nodes[child].output_link =
+                    if automaton.nodes[fail as usize].output != NONE {
+                        fail
+                    } else {
+                        automaton.nodes[fail as usize].output_link
+                    };
+                child += 1;
+            }
+
+            node += 1;
+        }
+
+        automaton
+    }
+
+    /// Find the child of the node with the given label
+    const fn child(&self, node: u16, c: char) -> u16 {
+        if node == ROOT && (c as u32).wrapping_sub(BLOCK_START) < BLOCK_LEN as u32 {
+            return self.root[(c as u32 - BLOCK_START) as usize];
+        }
+
+        let Node {
+            first_child,
+            children,
+            ..
+        } = self.nodes[node as usize];
+
+        let (mut low, mut high) = (first_child, first_child + children);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let label = self.nodes[mid as usize].label as u32;
+            if label == c as u32 {
+                return mid;
+            } else if label < c as u32 {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        NONE
+    }
+
+    /// Check if a ligature can start with the given character, a text
+    /// without any of these characters doesn't have any ligature in it.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn can_start_match(&self, c: char) -> bool {
+        self.child(ROOT, c) != NONE
+    }
+
+    /// The state that we go to after seeing `c` in the given state
+    pub(crate) const fn next_state(&self, mut state: u16, c: char) -> u16 {
+        loop {
+            let child = self.child(state, c);
+            if child != NONE {
+                return child;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.nodes[state as usize].fail;
+        }
+    }
+
+    /// The ligatures that end in the given state, see [`next_match`](Self::next_match)
+    pub(crate) const fn matches(&self, state: u16) -> Matches {
+        let node = match self.nodes[state as usize].output {
+            NONE => self.nodes[state as usize].output_link,
+            _ => state,
+        };
+
+        Matches {
+            node,
+            output: match node {
+                NONE => NONE,
+                _ => self.nodes[node as usize].output,
+            },
+        }
+    }
+
+    /// Return the next ligature of the matches and the number of characters in it
+    pub(crate) const fn next_match(&self, matches: &mut Matches) -> Option<(usize, usize)> {
+        if matches.node == NONE {
+            return None;
+        }
+
+        let Output { ligature, next } = self.outputs[matches.output as usize - 1];
+        let depth = self.nodes[matches.node as usize].depth;
+
+        matches.output = next;
+        if next == NONE {
+            matches.node = self.nodes[matches.node as usize].output_link;
+            if matches.node != NONE {
+                matches.output = self.nodes[matches.node as usize].output;
+            }
+        }
+
+        Some((ligature as usize, depth as usize))
+    }
+
+    /// Call the given function for every ligature found in the text, overlapping matches
+    /// are included too. Ranges are character positions in the text.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn find_overlapping<I, F>(&self, text: I, mut f: F)
+    where
+        I: IntoIterator<Item = char>,
+        F: FnMut(usize, Range<usize>),
+    {
+        let mut state = ROOT;
+
+        for (idx, c) in text.into_iter().enumerate() {
+            state = self.next_state(state, c);
+
+            let mut matches = self.matches(state);
+            while let Some((ligature, len)) = self.next_match(&mut matches) {
+                f(ligature, idx + 1 - len..idx + 1);
+            }
+        }
+    }
+}
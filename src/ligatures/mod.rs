@@ -14,6 +14,11 @@
 // form. If no replacement is specified for a form, then no replacement of
 // <match> will occur.
 //
+// A position in <match> can accept more then one character by putting them
+// inside brackets, `"\u{0631}[\u{06CC}\u{064A}]"` match both `ری` and `ري`.
+// Ligatures that end with `whole_word` only match when there is no other
+// letter right before or after them.
+//
 // Order here is important, it should be:
 //   1. Sentences
 //   2. Words
@@ -24,12 +29,75 @@ use core::ops::Range;
 
 use crate::form::Forms;
 
+use automaton::Automaton;
+
+pub(crate) mod automaton;
+
 pub(crate) const SENTENCES_LIGATURES_RANGE: Range<usize> = 0..3;
 pub(crate) const WORDS_LIGATURES_RANGE: Range<usize> = 3..12;
 pub(crate) const LETTERS_LIGATURES_RANGE: Range<usize> = 12..286;
 
+/// The text that is replaced by a ligature
+#[derive(Clone, Copy)]
+pub struct LigaturePattern {
+    /// The strings that are replaced by the ligature, each position can
+    /// have alternative characters inside brackets like `[یي]`
+    pub matches: &'static [&'static str],
+    /// Only match the pattern when it's not part of a longer word
+    pub whole_word: bool,
+}
+
+impl LigaturePattern {
+    /// Text of the pattern, using the first string and the first alternative of each position
+    #[cfg(feature = "alloc")]
+    pub fn text(&self) -> impl Iterator<Item = char> {
+        let mut chars = self.matches[0].chars();
+        core::iter::from_fn(move || match chars.next()? {
+            '[' => {
+                let first = chars.next();
+                chars.by_ref().find(|c| *c == ']');
+                first
+            }
+            c => Some(c),
+        })
+    }
+
+    /// Length of the longest string of the pattern in characters
+    #[cfg(feature = "alloc")]
+    pub(crate) fn max_len(&self) -> usize {
+        let len = |pattern: &str| {
+            let mut in_brackets = false;
+            pattern
+                .chars()
+                .filter(|c| match c {
+                    '[' => {
+                        in_brackets = true;
+                        true
+                    }
+                    ']' => {
+                        in_brackets = false;
+                        false
+                    }
+                    _ => !in_brackets,
+                })
+                .count()
+        };
+
+        self.matches.iter().map(|m| len(m)).max().unwrap_or(0)
+    }
+}
+
+macro_rules! whole_word {
+    () => {
+        false
+    };
+    (whole_word) => {
+        true
+    };
+}
+
 macro_rules! create_ligatures {
-    ($ligatures_size:literal, $($id:ident => ($words:expr, [$isolated:literal, $initial:literal, $medial:literal, $final:literal]),)*) => {
+    ($ligatures_size:literal, $($id:ident => ($words:expr, [$isolated:literal, $initial:literal, $medial:literal, $final:literal]$(, $whole_word:ident)?),)*) => {
         /// All the Ligature names.
         #[allow(non_camel_case_types)]
         #[derive(Copy, Clone, Eq, PartialEq, Hash)]
@@ -38,12 +106,43 @@ macro_rules! create_ligatures {
             $($id),*
         }
 
+        impl LigatureNames {
+            /// All the ligatures, in the same order as [`LIGATURES`]
+            pub const ALL: [Self; $ligatures_size] = [$(Self::$id),*];
+
+            /// Name of the ligature, like `ARABIC_LIGATURE_ALLAH`
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$id => stringify!($id)),*
+                }
+            }
+
+            /// Find the ligature with the given name, ignoring the case
+            pub fn from_name(name: &str) -> Option<Self> {
+                Self::ALL
+                    .into_iter()
+                    .find(|ligature| ligature.name().eq_ignore_ascii_case(name))
+            }
+        }
+
 
         /// All the supported ligatures
-        pub const LIGATURES: [(&[&'static str], Forms); $ligatures_size] = [
-            $(($words, Forms::new($isolated, $initial, $medial, $final))),*
+        pub const LIGATURES: [(LigaturePattern, Forms); $ligatures_size] = [
+            $((
+                LigaturePattern {
+                    matches: $words,
+                    whole_word: whole_word!($($whole_word)?),
+                },
+                Forms::new($isolated, $initial, $medial, $final),
+            )),*
         ];
 
+        /// Automaton used to find all the [`LIGATURES`] in a single pass.
+        pub(crate) static LIGATURES_AUTOMATON: Automaton<
+            { automaton::nodes_len(&LIGATURES) },
+            { automaton::outputs_len(&LIGATURES) },
+        > = Automaton::new(&LIGATURES);
+
     };
 }
 
@@ -53,61 +152,73 @@ create_ligatures! [
     ARABIC_LIGATURE_BISMILLAH_AR_RAHMAN_AR_RAHEEM => (
         &[concat!(
             "\u{0628}\u{0633}\u{0645}\u{0020}",
-            "\u{0627}\u{0644}\u{0644}\u{0647}\u{0020}",
+            "\u{0627}\u{0644}\u{0644}[\u{0647}\u{06C1}]\u{0020}",
             "\u{0627}\u{0644}\u{0631}\u{062D}\u{0645}\u{0646}\u{0020}",
-            "\u{0627}\u{0644}\u{0631}\u{062D}\u{064A}\u{0645}",
+            "\u{0627}\u{0644}\u{0631}\u{062D}[\u{064A}\u{06CC}]\u{0645}",
         )],
-        ['\u{FDFD}', '\0', '\0', '\0']
+        ['\u{FDFD}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_JALLAJALALOUHOU => (
-        &["\u{062C}\u{0644}\u{0020}\u{062C}\u{0644}\u{0627}\u{0644}\u{0647}"],
-        ['\u{FDFB}', '\0', '\0', '\0']
+        &["\u{062C}\u{0644}\u{0020}\u{062C}\u{0644}\u{0627}\u{0644}[\u{0647}\u{06C1}]"],
+        ['\u{FDFB}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_SALLALLAHOU_ALAYHE_WASALLAM => (
         &[concat!(
-            "\u{0635}\u{0644}\u{0649}\u{0020}",
-            "\u{0627}\u{0644}\u{0644}\u{0647}\u{0020}",
-            "\u{0639}\u{0644}\u{064A}\u{0647}\u{0020}",
+            "\u{0635}\u{0644}[\u{0649}\u{06CC}]\u{0020}",
+            "\u{0627}\u{0644}\u{0644}[\u{0647}\u{06C1}]\u{0020}",
+            "\u{0639}\u{0644}[\u{064A}\u{06CC}][\u{0647}\u{06C1}]\u{0020}",
             "\u{0648}\u{0633}\u{0644}\u{0645}",
         )],
-        ['\u{FDFA}', '\0', '\0', '\0']
+        ['\u{FDFA}', '\0', '\0', '\0'],
+        whole_word
     ),
 
     ARABIC_LIGATURE_ALLAH => (
-        &["\u{0627}\u{0644}\u{0644}\u{0647}"],
-        ['\u{FDF2}', '\0', '\0', '\0']
+        &["\u{0627}\u{0644}\u{0644}[\u{0647}\u{06C1}]"],
+        ['\u{FDF2}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_AKBAR => (
-        &["\u{0623}\u{0643}\u{0628}\u{0631}"],
-        ['\u{FDF3}', '\0', '\0', '\0']
+        &["\u{0623}[\u{0643}\u{06A9}]\u{0628}\u{0631}"],
+        ['\u{FDF3}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_ALAYHE => (
-        &["\u{0639}\u{0644}\u{064A}\u{0647}"],
-        ['\u{FDF7}', '\0', '\0', '\0']
+        &["\u{0639}\u{0644}[\u{064A}\u{06CC}][\u{0647}\u{06C1}]"],
+        ['\u{FDF7}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_MOHAMMAD => (
         &["\u{0645}\u{062D}\u{0645}\u{062F}"],
-        ['\u{FDF4}', '\0', '\0', '\0']
+        ['\u{FDF4}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_RASOUL => (
         &["\u{0631}\u{0633}\u{0648}\u{0644}"],
-        ['\u{FDF6}', '\0', '\0', '\0']
+        ['\u{FDF6}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_SALAM => (
         &["\u{0635}\u{0644}\u{0639}\u{0645}"],
-        ['\u{FDF5}', '\0', '\0', '\0']
+        ['\u{FDF5}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_SALLA => (
-        &["\u{0635}\u{0644}\u{0649}"],
-        ['\u{FDF9}', '\0', '\0', '\0']
+        &["\u{0635}\u{0644}[\u{0649}\u{06CC}]"],
+        ['\u{FDF9}', '\0', '\0', '\0'],
+        whole_word
     ),
     ARABIC_LIGATURE_WASALLAM => (
         &["\u{0648}\u{0633}\u{0644}\u{0645}"],
-        ['\u{FDF8}', '\0', '\0', '\0']
+        ['\u{FDF8}', '\0', '\0', '\0'],
+        whole_word
     ),
     RIAL_SIGN => (
         &["\u{0631}[\u{06CC}\u{064A}]\u{0627}\u{0644}"],
-        ['\u{FDFC}', '\0', '\0', '\0']
+        ['\u{FDFC}', '\0', '\0', '\0'],
+        whole_word
     ),
 
     ARABIC_LIGATURE_AIN_WITH_ALEF_MAKSURA => (
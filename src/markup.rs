@@ -0,0 +1,434 @@
+//! Reshaping text that has tags or placeholders in it.
+//!
+//! The text is split to [`Token`]s by a [`Tokenizer`], the text of the tokens is reshaped as
+//! a whole so the letters around the tags are still joined, and the tags are put back where
+//! they were. A ligature is never made of the letters on both sides of a tag, so every letter
+//! stays inside its tags. Tokenizers can be combined with a tuple, the first one that finds a
+//! token wins.
+//! ```rust
+//! use ar_reshaper::{
+//!     markup::{Html, Placeholders},
+//!     ArabicReshaper,
+//! };
+//!
+//! let reshaper = ArabicReshaper::default();
+//!
+//! assert_eq!(
+//!     reshaper.reshape_markup("سل<b>ا</b>م {0}", (Html, Placeholders)),
+//!     "ﺳﻠ<b>ﺎ</b>ﻡ {0}"
+//! );
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::{segment::Scratch, ArabicReshaper};
+
+/// Character that is reshaped in place of a [`Token::Placeholder`], it doesn't join
+/// the letters around it and the visual ordering moves it as a single character.
+const OBJECT_REPLACEMENT: char = '\u{FFFC}';
+
+/// A part of a text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// Text that should be reshaped
+    Text(&'a str),
+    /// Tag that is written as it is, the letters before and after it are joined
+    Tag(&'a str),
+    /// Text that is written as it is and is like a single character that doesn't
+    /// join the letters around it, like `%s` or `{name}`
+    Placeholder(&'a str),
+    /// Line break that is written as it is, the text before and after it are reshaped separately
+    Break(&'a str),
+}
+
+/// Find the tokens of a markup language in a text.
+pub trait Tokenizer {
+    /// Return the token at the start of the text and its length in bytes, or [`None`]
+    /// if the text doesn't start with a token. The text around the tokens is [`Token::Text`].
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)>;
+}
+
+impl<T: Tokenizer + ?Sized> Tokenizer for &mut T {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        (**self).token(text)
+    }
+}
+
+macro_rules! impl_tuple_tokenizer {
+    ($($name:ident)+) => {
+        impl<$($name: Tokenizer),+> Tokenizer for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+                let ($($name,)+) = self;
+                None$(.or_else(|| $name.token(text)))+
+            }
+        }
+    };
+}
+
+impl_tuple_tokenizer!(A B);
+impl_tuple_tokenizer!(A B C);
+impl_tuple_tokenizer!(A B C D);
+
+/// HTML and XML tags (`<b>`, `</b>`, `<font color="red">`) and comments are tags, `<br>`
+/// is a line break and the entities (`&amp;`, `&#x627;`) are placeholders.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Html;
+
+impl Tokenizer for Html {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        match text.as_bytes() {
+            [b'<', b'!', b'-', b'-', ..] => {
+                let len = text.find("-->").map_or(text.len(), |end| end + "-->".len());
+                Some((Token::Tag(&text[..len]), len))
+            }
+            [b'&', ..] => entity_len(text).map(|len| (Token::Placeholder(&text[..len]), len)),
+            _ => angle_tag(text),
+        }
+    }
+}
+
+/// Tags of Unity and TextMesh Pro rich text (`<b>`, `<color=#ff0000>`, `</color>`),
+/// `<br>` is a line break and the sprites (`<sprite=0>`) are placeholders.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnityRichText;
+
+impl Tokenizer for UnityRichText {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        match angle_tag(text)? {
+            (Token::Tag(tag), len) if tag_name(tag).eq_ignore_ascii_case("sprite") => {
+                Some((Token::Placeholder(tag), len))
+            }
+            token => Some(token),
+        }
+    }
+}
+
+/// BBCode tags, like `[b]`, `[url=https://example.com]` and `[/url]`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BBCode;
+
+impl Tokenizer for BBCode {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        let name = text.strip_prefix('[')?;
+        let name = name.strip_prefix('/').unwrap_or(name);
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        let end = text[1..].find([']', '[', '\n'])? + 1;
+        (text.as_bytes()[end] == b']').then(|| (Token::Tag(&text[..=end]), end + 1))
+    }
+}
+
+/// Format placeholders, the printf ones (`%s`, `%1$d`, `%.2f`) and the ones
+/// in braces (`{0}`, `{name}`, `{0:N2}`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Placeholders;
+
+impl Tokenizer for Placeholders {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        let len = match text.as_bytes()[0] {
+            b'%' => printf_len(text)?,
+            b'{' => brace_len(text)?,
+            _ => return None,
+        };
+        Some((Token::Placeholder(&text[..len]), len))
+    }
+}
+
+/// Tag in angle brackets at the start of the text, `<br>` is a line break
+pub(crate) fn angle_tag(text: &str) -> Option<(Token<'_>, usize)> {
+    let rest = text.strip_prefix('<')?;
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/') {
+        return None;
+    }
+
+    let len = text.find('>')? + 1;
+    let tag = &text[..len];
+    match !rest.starts_with('/') && tag_name(tag).eq_ignore_ascii_case("br") {
+        true => Some((Token::Break(tag), len)),
+        false => Some((Token::Tag(tag), len)),
+    }
+}
+
+/// Name of the tag, like `color` in `<color=#ff0000>`
+fn tag_name(tag: &str) -> &str {
+    let name = tag.trim_start_matches(['<', '/']);
+    let end = name
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '_' && c != ':')
+        .unwrap_or(name.len());
+    &name[..end]
+}
+
+/// Length of the placeholder in braces without any other brace in it, like `{0}` or `{name}`
+pub(crate) fn brace_len(text: &str) -> Option<usize> {
+    let end = text[1..].find(['{', '}'])? + 1;
+    (text.as_bytes()[end] == b'}').then_some(end + 1)
+}
+
+/// Length of the printf conversion specification at the start of the text, like `%s`, `%1$d` or `%-5.2f`
+pub(crate) fn printf_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut idx = 1;
+    let digits = |idx: &mut usize| {
+        while bytes.get(*idx).is_some_and(u8::is_ascii_digit) {
+            *idx += 1;
+        }
+    };
+
+    if bytes.get(1) == Some(&b'%') {
+        return Some(2);
+    }
+
+    // argument position
+    let position = idx;
+    digits(&mut idx);
+    if bytes.get(idx) == Some(&b'$') && idx > position {
+        idx += 1;
+    } else {
+        idx = position;
+    }
+
+    while bytes.get(idx).is_some_and(|b| b"-+ #0'".contains(b)) {
+        idx += 1;
+    }
+    // width and precision
+    digits(&mut idx);
+    if bytes.get(idx) == Some(&b'.') {
+        idx += 1;
+        digits(&mut idx);
+    }
+    for length in ["hh", "ll", "h", "l", "L", "q", "j", "z", "t"] {
+        if text[idx..].starts_with(length) {
+            idx += length.len();
+            break;
+        }
+    }
+
+    bytes
+        .get(idx)
+        .is_some_and(|b| b"diouxXeEfFgGaAcspn@".contains(b))
+        .then_some(idx + 1)
+}
+
+/// Split the text with the given function, that return the token at the start
+/// of the text and its length or [`None`] if the text doesn't start with a token.
+pub(crate) fn tokens<'a, F>(text: &'a str, mut token: F) -> Vec<Token<'a>>
+where
+    F: FnMut(&'a str) -> Option<(Token<'a>, usize)>,
+{
+    let mut result = Vec::new();
+    let (mut start, mut idx) = (0, 0);
+
+    while idx < text.len() {
+        match token(&text[idx..]) {
+            Some((found, len)) => {
+                if start < idx {
+                    result.push(Token::Text(&text[start..idx]));
+                }
+                result.push(found);
+                idx += len;
+                start = idx;
+            }
+            None => idx += text[idx..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    if start < text.len() {
+        result.push(Token::Text(&text[start..]));
+    }
+    result
+}
+
+/// Length of the HTML or XML entity (like `&amp;` or `&#x627;`) at the start of the text
+pub(crate) fn entity_len(text: &str) -> Option<usize> {
+    let end = text.find(';')?;
+    let name = text[..end].strip_prefix('&')?;
+    let name = name.strip_prefix('#').unwrap_or(name);
+
+    let valid =
+        !name.is_empty() && name.len() <= 32 && name.bytes().all(|b| b.is_ascii_alphanumeric());
+    valid.then_some(end + 1)
+}
+
+/// Reshape the text of the tokens and put the other tokens back where they were in the text.
+pub(crate) fn reshape_tokens(reshaper: &ArabicReshaper, tokens: &[Token], result: &mut String) {
+    let mut text = String::new();
+    // tags and placeholders, with their position in the text
+    let mut tags = Vec::new();
+    let mut placeholders = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Text(t) => text.push_str(t),
+            Token::Tag(tag) => tags.push((text.len(), *tag)),
+            Token::Placeholder(placeholder) => {
+                placeholders.push((text.len(), *placeholder));
+                text.push(OBJECT_REPLACEMENT);
+            }
+            Token::Break(line_break) => {
+                reshape_tagged(reshaper, &text, &tags, &placeholders, result);
+                result.push_str(line_break);
+                text.clear();
+                tags.clear();
+                placeholders.clear();
+            }
+        }
+    }
+
+    reshape_tagged(reshaper, &text, &tags, &placeholders, result);
+}
+
+fn reshape_tagged(
+    reshaper: &ArabicReshaper,
+    text: &str,
+    tags: &[(usize, &str)],
+    placeholders: &[(usize, &str)],
+    result: &mut String,
+) {
+    // no ligature goes over a tag, so the letters inside the tag are kept in it
+    let (mut reshaped, mut map) = (String::new(), Vec::new());
+    let breaks: Vec<usize> = tags.iter().map(|(position, _)| *position).collect();
+    // it can't fail when it's not strict
+    let _ = reshaper.reshape_impl(
+        text,
+        Some(&mut map),
+        &breaks,
+        false,
+        &mut Scratch::default(),
+        &mut reshaped,
+    );
+    let chars: Vec<(char, Range<usize>)> = reshaped.chars().zip(map).collect();
+
+    // the characters that each tag is written before, and the order of the tags before them
+    #[cfg(feature = "bidi")]
+    let mut slots = match reshaper.config().visual_ordering {
+        true => visual_slots(tags, &chars),
+        false => logical_slots(tags, &chars),
+    };
+    #[cfg(not(feature = "bidi"))]
+    let mut slots = logical_slots(tags, &chars);
+    slots.sort_unstable();
+
+    let mut slots = slots.into_iter().peekable();
+    for (idx, (c, source)) in chars.iter().enumerate() {
+        while let Some((.., tag)) = slots.next_if(|(slot, ..)| *slot <= idx) {
+            result.push_str(tags[tag].1);
+        }
+
+        let placeholder = match c {
+            &OBJECT_REPLACEMENT => placeholders
+                .binary_search_by_key(&source.start, |(position, _)| *position)
+                .ok(),
+            _ => None,
+        };
+        match placeholder {
+            Some(idx) => result.push_str(placeholders[idx].1),
+            None => result.push(*c),
+        }
+    }
+
+    for (.., tag) in slots {
+        result.push_str(tags[tag].1);
+    }
+}
+
+/// A tag is written before the first character that comes after it in the text
+fn logical_slots(
+    tags: &[(usize, &str)],
+    chars: &[(char, Range<usize>)],
+) -> Vec<(usize, u8, usize)> {
+    tags.iter()
+        .enumerate()
+        .map(|(tag, (position, _))| {
+            let next = chars
+                .iter()
+                .position(|(_, source)| source.start >= *position)
+                .unwrap_or(chars.len());
+            (next, 0, tag)
+        })
+        .collect()
+}
+
+/// The characters are in visual order, so a pair of tags is written around the characters
+/// that are between them in the text, the open tag before the first one and the close
+/// tag after the last one. Tags that aren't a pair are written between the characters
+/// that are before and after them in the text.
+#[cfg(feature = "bidi")]
+fn visual_slots(tags: &[(usize, &str)], chars: &[(char, Range<usize>)]) -> Vec<(usize, u8, usize)> {
+    // the close tags come first, then the tags that aren't a pair and then the open tags
+    const CLOSE: u8 = 0;
+    const SINGLE: u8 = 1;
+    const OPEN: u8 = 2;
+
+    let mut slots = Vec::with_capacity(tags.len());
+    let mut paired = alloc::vec![false; tags.len()];
+    let mut open: Vec<(usize, &str)> = Vec::new();
+
+    for (tag, (position, text)) in tags.iter().enumerate() {
+        match pair_tag(text) {
+            Some((name, false)) => open.push((tag, name)),
+            Some((name, true)) => {
+                let Some(idx) = open.iter().rposition(|(_, n)| n.eq_ignore_ascii_case(name)) else {
+                    continue;
+                };
+                let first = open[idx].0;
+                open.truncate(idx);
+
+                let inside = chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, source))| (tags[first].0..*position).contains(&source.start))
+                    .map(|(idx, _)| idx);
+                let (Some(start), Some(end)) = (inside.clone().min(), inside.max()) else {
+                    continue;
+                };
+
+                slots.push((start, OPEN, first));
+                slots.push((end + 1, CLOSE, tag));
+                paired[first] = true;
+                paired[tag] = true;
+            }
+            None => {}
+        }
+    }
+
+    for (tag, (position, _)) in tags.iter().enumerate() {
+        if paired[tag] {
+            continue;
+        }
+
+        let (before, after): (Vec<_>, Vec<_>) = chars
+            .iter()
+            .enumerate()
+            .partition(|(_, (_, source))| source.start < *position);
+        let previous = before.iter().max_by_key(|(_, (_, source))| source.start);
+        let next = after.iter().min_by_key(|(_, (_, source))| source.start);
+
+        let slot = match (previous.map(|(idx, _)| *idx), next.map(|(idx, _)| *idx)) {
+            // between the characters when they are next to each other
+            (Some(previous), Some(next)) if previous.abs_diff(next) == 1 => previous.max(next),
+            (_, Some(next)) => next,
+            (Some(previous), None) => previous + 1,
+            (None, None) => chars.len(),
+        };
+        slots.push((slot, SINGLE, tag));
+    }
+
+    slots
+}
+
+/// Name of the HTML or BBCode tag and if it's a close tag, [`None`] for the tags that
+/// aren't a part of a pair (like comments and self-closing tags).
+#[cfg(feature = "bidi")]
+fn pair_tag(tag: &str) -> Option<(&str, bool)> {
+    let inner = tag.strip_prefix(['<', '['])?;
+    if inner.starts_with('!') || tag.ends_with("/>") {
+        return None;
+    }
+
+    let name = tag_name(inner);
+    (!name.is_empty()).then_some((name, inner.starts_with('/')))
+}
@@ -1,20 +1,28 @@
 use core::ops::RangeInclusive;
+#[cfg(feature = "alloc")]
+use core::{convert::Infallible, fmt, ops::Range};
 
-use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
 
+#[cfg(feature = "alloc")]
 use crate::{
-    config::ReshaperConfig,
-    form::LetterForm,
-    letters::{
-        letters_db::{TATWEEL, ZWJ},
-        *,
+    config::FormsFallback,
+    letters::letters_db::{
+        LETTERS_ARABIC, LETTERS_ARABIC_V2, LETTERS_KURDISH, LETTERS_WITHOUT_FORMS, TATWEEL, ZWJ,
     },
     ligatures::*,
+    markup::{self, Tokenizer},
+    segment::Scratch,
+};
+use crate::{
+    config::ReshaperConfig,
+    error::ReshapeError,
+    letters::{Letters, LettersIndex},
+    segment,
 };
 
-const EMPTY: (char, LetterForm) = ('\0', LetterForm::Unsupported);
-
-static HARAKAT_RE: [RangeInclusive<char>; 9] = [
+pub(crate) static HARAKAT_RE: [RangeInclusive<char>; 9] = [
     '\u{0610}'..='\u{061a}',
     '\u{064b}'..='\u{065f}',
     '\u{0670}'..='\u{0670}',
@@ -26,202 +34,594 @@ static HARAKAT_RE: [RangeInclusive<char>; 9] = [
     '\u{08e3}'..='\u{08ff}',
 ];
 
+/// Check if the character is a haraka
+pub(crate) const fn is_haraka(c: char) -> bool {
+    let mut idx = 0;
+    while idx < HARAKAT_RE.len() {
+        if c >= *HARAKAT_RE[idx].start() && c <= *HARAKAT_RE[idx].end() {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+/// Split the line terminator (`\n` or `\r\n`) from the end of the line
+#[cfg(any(feature = "std", feature = "subtitles", feature = "catalogs"))]
+pub(crate) fn split_terminator(line: &str) -> (&str, &str) {
+    let content = line
+        .strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+    line.split_at(content.len())
+}
+
+/// A buffer that keeps the memory used for reshaping between the calls, so
+/// reshaping a lot of text (like every label of every frame) doesn't allocate
+/// once the buffer is big enough.
+/// ```rust
+/// use ar_reshaper::{ArabicReshaper, ReshapeBuffer};
+///
+/// let reshaper = ArabicReshaper::default();
+/// let mut buffer = ReshapeBuffer::new();
+///
+/// for (label, reshaped) in [("سلام", "ﺳﻼﻡ"), ("دنیا", "ﺩﻧﯿﺎ")] {
+///     assert_eq!(buffer.reshape(&reshaper, label), reshaped);
+/// }
+/// ```
+#[derive(Clone, Default)]
+#[cfg(feature = "alloc")]
+pub struct ReshapeBuffer {
+    scratch: Scratch,
+    result: String,
+}
+
+#[cfg(feature = "alloc")]
+impl ReshapeBuffer {
+    /// Create a new empty [ReshapeBuffer]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reshape the given line with the reshaper, the result is kept in the buffer until the next call
+    pub fn reshape<S>(&mut self, reshaper: &ArabicReshaper, text: S) -> &str
+    where
+        S: AsRef<str>,
+    {
+        self.result.clear();
+        // it can't fail when it's not strict
+        let _ = reshaper.reshape_impl(
+            text.as_ref(),
+            None,
+            &[],
+            false,
+            &mut self.scratch,
+            &mut self.result,
+        );
+        &self.result
+    }
+
+    /// Reshape the given line with the reshaper and push the reshaped string to the end of `out`
+    pub fn reshape_into<S>(&mut self, reshaper: &ArabicReshaper, text: S, out: &mut String)
+    where
+        S: AsRef<str>,
+    {
+        // it can't fail when it's not strict
+        let _ = reshaper.reshape_impl(text.as_ref(), None, &[], false, &mut self.scratch, out);
+    }
+
+    /// Reshape the given line with the reshaper and write the reshaped string to `out`
+    /// ```rust
+    /// use core::fmt::Write;
+    /// use ar_reshaper::{ArabicReshaper, ReshapeBuffer};
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    /// let mut buffer = ReshapeBuffer::new();
+    ///
+    /// let mut out = String::new();
+    /// for word in ["سلام", "دنیا"] {
+    ///     buffer.reshape_fmt(&reshaper, word, &mut out).unwrap();
+    ///     out.write_char(' ').unwrap();
+    /// }
+    ///
+    /// assert_eq!(out, "ﺳﻼﻡ ﺩﻧﯿﺎ ");
+    /// ```
+    pub fn reshape_fmt<S, W>(
+        &mut self,
+        reshaper: &ArabicReshaper,
+        text: S,
+        out: &mut W,
+    ) -> fmt::Result
+    where
+        S: AsRef<str>,
+        W: fmt::Write + ?Sized,
+    {
+        reshaper.reshape_fmt_impl(text.as_ref(), &mut self.scratch, out)
+    }
+}
+
 /// # ArabicReshaper
 /// the main type for reconstructing sentences to be used in applications that don't support Arabic script.
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct ArabicReshaper {
     config: ReshaperConfig,
-    letters: Letters,
+    letters_index: LettersIndex,
+}
+
+impl Default for ArabicReshaper {
+    fn default() -> Self {
+        Self::new(ReshaperConfig::default())
+    }
 }
 
 impl ArabicReshaper {
     /// Create a new [ArabicReshaper] using the given config
     pub const fn new(config: ReshaperConfig) -> Self {
         Self {
-            letters: Letters::new(config.language),
+            letters_index: LettersIndex::for_language(&config.language),
             config,
         }
     }
 
+    /// The config of the reshaper
+    pub(crate) const fn config(&self) -> &ReshaperConfig {
+        &self.config
+    }
+
+    /// The letters of the current language
+    pub(crate) const fn letters(&self) -> Letters<'_> {
+        Letters::new(&self.config.language, &self.letters_index)
+    }
+
     /// Check whatever the text need reshaping or not.
     pub fn need_reshape<S>(&self, text: S) -> bool
     where
         S: AsRef<str>,
     {
-        text.as_ref().chars().any(|c| self.letters.contains_key(&c))
+        let letters = self.letters();
+        text.as_ref().chars().any(|c| letters.contains_key(&c))
     }
 
-    /// Reshape the given line and return the reshaped string
-    pub fn reshape<S>(&self, text: S) -> String
-    where
-        S: AsRef<str>,
-    {
-        let text = text.as_ref();
-
-        if text.is_empty() {
-            return String::new();
-        }
-
+    /// Check if reshaping doesn't change the text, this is more strict then
+    /// [`need_reshape`](Self::need_reshape) and looks for everything that the config would change.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_unchanged(&self, text: &str) -> bool {
         let ReshaperConfig {
             delete_harakat,
             shift_harakat_position,
             delete_tatweel,
-            support_zwj,
-            use_unshaped_instead_of_isolated,
             support_ligatures,
+            ref custom_ligatures,
             ..
         } = self.config;
 
-        let isolated_form = match use_unshaped_instead_of_isolated {
-            true => LetterForm::Unshaped,
-            false => LetterForm::Isolated,
-        };
-
-        let mut output = Vec::new();
-        let mut position_harakat: BTreeMap<isize, Vec<char>> = BTreeMap::new();
-
-        for letter in text.chars() {
-            if HARAKAT_RE.iter().any(|h| h.contains(&letter)) {
-                if !delete_harakat {
-                    let mut position = (output.len() - 1) as isize;
-                    if shift_harakat_position {
-                        position -= 1
-                    }
-
-                    let entry = position_harakat.entry(position).or_default();
-
-                    if shift_harakat_position {
-                        entry.insert(0, letter);
-                    } else {
-                        entry.push(letter);
-                    }
-                }
-            } else if letter == TATWEEL && delete_tatweel || letter == ZWJ && !support_zwj {
-            } else if !self.letters.contains_key(&letter) {
-                output.push((letter, LetterForm::Unsupported))
-            } else if output.is_empty() {
-                output.push((letter, isolated_form)) // first letter
-            } else {
-                let previous_letter = output.last_mut().unwrap();
-                if (previous_letter.1 == LetterForm::Unsupported)
-                    || (!self.letters.connects_with_letter_before(letter))
-                    || (!self.letters.connects_with_letter_after(previous_letter.0))
-                    || (previous_letter.1 == LetterForm::Final
-                        && !self
-                            .letters
-                            .connects_with_letters_before_and_after(previous_letter.0))
-                {
-                    output.push((letter, isolated_form));
-                } else if previous_letter.1 == isolated_form {
-                    *previous_letter = (previous_letter.0, LetterForm::Initial);
-                    output.push((letter, LetterForm::Final));
-                } else {
-                    // Otherwise, we will change the previous letter to connect
-                    // to the current letter
-                    *previous_letter = (previous_letter.0, LetterForm::Medial);
-                    output.push((letter, LetterForm::Final));
-                }
+        let letters = self.letters();
+        let unchanged = text.chars().all(|c| {
+            if HARAKAT_RE.iter().any(|h| h.contains(&c)) {
+                // shifted harakat are moved before the previous character
+                return !delete_harakat && !shift_harakat_position;
             }
 
-            // Remove ZWJ if it's the second to last item as it won't be useful
-            let len = output.len();
-            if support_zwj && len > 1 && output[len - 2].0 == ZWJ {
-                output.remove(len - 2);
-            }
+            // ZWJ is always removed, even when it's supported
+            !(letters.contains_key(&c)
+                || c == ZWJ
+                || c == TATWEEL && delete_tatweel
+                || self.config.language.normalize(c, false) != c
+                || support_ligatures
+                    && (LIGATURES_AUTOMATON.can_start_match(c)
+                        || custom_ligatures.iter().any(|l| l.text.starts_with(c))))
+        });
+
+        if !unchanged {
+            return false;
         }
 
-        if support_zwj && !output.is_empty() && output.last().unwrap().0 == ZWJ {
-            output.pop();
+        #[cfg(feature = "bidi")]
+        if self.config.visual_ordering {
+            return crate::bidi::visual_order(text) == text;
         }
 
-        if support_ligatures {
-            // Clean text from Harakat to be able to find ligatures
-            let mut text: String = text
-                .chars()
-                .filter(|c| !HARAKAT_RE.iter().any(|r| r.contains(c)))
-                .collect();
+        true
+    }
 
-            // Clean text from Tatweel to find ligatures if delete_tatweel
-            if delete_tatweel {
-                text = text.replace(TATWEEL, "")
-            }
+    /// Reshape the given line, the text is borrowed when there is nothing to reshape in it.
+    ///
+    /// This is the same as checking the text with [`need_reshape`](Self::need_reshape) before
+    /// reshaping it, but it also keeps the harakat and tatweel that the config would delete in mind.
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use ar_reshaper::ArabicReshaper;
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    ///
+    /// assert!(matches!(reshaper.reshape_cow("no reshape"), Cow::Borrowed("no reshape")));
+    /// assert!(matches!(reshaper.reshape_cow("سلام"), Cow::Owned(_)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reshape_cow<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.is_unchanged(text) {
+            true => Cow::Borrowed(text),
+            false => Cow::Owned(self.reshape(text)),
+        }
+    }
 
-            for ((tmatchs, forms), enabled) in
-                LIGATURES.iter().zip(self.config.ligatures.list.iter())
-            {
-                if !enabled {
-                    continue;
-                }
-                for tmatch in *tmatchs {
-                    for (idx, m) in text.match_indices(tmatch) {
-                        // match_indices returns bytes offset
-                        // we want character position
-                        let a = text[..idx].chars().count();
-                        let b = text[..idx + m.len()].chars().count();
-
-                        let a_form = output[a].1;
-                        let b_form = output[b - 1].1;
-                        let ligature_form: LetterForm;
-
-                        // +-----------+----------+---------+---------+----------+
-                        // | a   \   b | ISOLATED | INITIAL | MEDIAL  | FINAL    |
-                        // +-----------+----------+---------+---------+----------+
-                        // | ISOLATED  | ISOLATED | INITIAL | INITIAL | ISOLATED |
-                        // | INITIAL   | ISOLATED | INITIAL | INITIAL | ISOLATED |
-                        // | MEDIAL    | FINAL    | MEDIAL  | MEDIAL  | FINAL    |
-                        // | FINAL     | FINAL    | MEDIAL  | MEDIAL  | FINAL    |
-                        // +-----------+----------+---------+---------+----------+
-
-                        if a_form == isolated_form || a_form == LetterForm::Initial {
-                            if b_form == isolated_form || b_form == LetterForm::Final {
-                                ligature_form = LetterForm::Isolated;
-                            } else {
-                                ligature_form = LetterForm::Initial;
-                            }
-                        } else if b_form == isolated_form || b_form == LetterForm::Final {
-                            ligature_form = LetterForm::Final;
-                        } else {
-                            ligature_form = LetterForm::Medial;
-                        }
-
-                        if forms.get(ligature_form) == '\0' {
-                            continue;
-                        }
-
-                        output[a] = (forms.get(ligature_form), LetterForm::Unsupported);
-
-                        for e in output[a + 1..b].iter_mut() {
-                            *e = EMPTY;
-                        }
-                    }
-                }
-            }
+    /// Reshape the given line and return the reshaped string
+    #[cfg(feature = "alloc")]
+    pub fn reshape<S>(&self, text: S) -> String
+    where
+        S: AsRef<str>,
+    {
+        let mut result = String::new();
+        self.reshape_into(text, &mut result);
+        result
+    }
+
+    /// Reshape the given line and push the reshaped string to the end of `out`.
+    ///
+    /// Use [`ReshapeBuffer::reshape_into`] to keep the memory that is used for the words that
+    /// are too long to be reshaped on the stack between the calls.
+    #[cfg(feature = "alloc")]
+    pub fn reshape_into<S>(&self, text: S, out: &mut String)
+    where
+        S: AsRef<str>,
+    {
+        // it can't fail when it's not strict
+        let _ = self.reshape_impl(
+            text.as_ref(),
+            None,
+            &[],
+            false,
+            &mut Scratch::default(),
+            out,
+        );
+    }
+
+    /// Reshape the given line and write the reshaped string to `out`, a word at a time.
+    ///
+    /// Use [`ReshapeBuffer::reshape_fmt`] to keep the memory that is used for the words that
+    /// are too long to be reshaped on the stack between the calls.
+    #[cfg_attr(
+        feature = "bidi",
+        doc = "When `visual_ordering` is enabled the whole line is reshaped first, to be reordered."
+    )]
+    /// ```rust
+    /// use core::fmt::Write;
+    /// use ar_reshaper::ArabicReshaper;
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    /// let mut out = String::from("> ");
+    /// reshaper.reshape_fmt("سلام", &mut out).unwrap();
+    ///
+    /// assert_eq!(out, "> ﺳﻼﻡ");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reshape_fmt<S, W>(&self, text: S, out: &mut W) -> fmt::Result
+    where
+        S: AsRef<str>,
+        W: fmt::Write + ?Sized,
+    {
+        self.reshape_fmt_impl(text.as_ref(), &mut Scratch::default(), out)
+    }
+
+    /// Reshape the given line and return the reshaped string, or an error if the
+    /// letters of the language can't reshape it.
+    ///
+    /// [`reshape`](Self::reshape) write the letters that can't be reshaped as they are,
+    /// this method can be used to find these letters, like when you are testing a custom language.
+    /// ```rust
+    /// use ar_reshaper::{form::Forms, ArabicReshaper, Language, ReshapeError, ReshaperConfig};
+    ///
+    /// // a letter that only have the medial form
+    /// static LETTERS: [(char, Forms); 1] = [('\u{0628}', Forms::new('\0', '\0', '\u{FE92}', '\0'))];
+    ///
+    /// let reshaper = ArabicReshaper::new(ReshaperConfig {
+    ///     language: Language::Custom(&LETTERS),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(
+    ///     reshaper.try_reshape("ب"),
+    ///     Err(ReshapeError::MissingForm { letter: 'ب', position: 0 })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_reshape<S>(&self, text: S) -> Result<String, ReshapeError>
+    where
+        S: AsRef<str>,
+    {
+        let mut result = String::new();
+        self.reshape_impl(
+            text.as_ref(),
+            None,
+            &[],
+            true,
+            &mut Scratch::default(),
+            &mut result,
+        )?;
+        Ok(result)
+    }
+
+    /// Reshape the given line and return the reshaped string and the position of
+    /// each character of it in the given text.
+    ///
+    /// For every [`char`] of the reshaped string there is a byte range of the text that
+    /// produced it, ligatures cover all of their letters and the characters that were
+    /// removed (like deleted harakat and tatweel) are not covered by any range.
+    /// ```rust
+    /// use ar_reshaper::ArabicReshaper;
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    /// let (reshaped, map) = reshaper.reshape_with_map("لا بد");
+    ///
+    /// assert_eq!(reshaped, "ﻻ ﺑﺪ");
+    /// assert_eq!(map, [0..4, 4..5, 5..7, 7..9]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reshape_with_map<S>(&self, text: S) -> (String, Vec<Range<usize>>)
+    where
+        S: AsRef<str>,
+    {
+        let (mut result, mut map) = (String::new(), Vec::new());
+        // it can't fail when it's not strict
+        let _ = self.reshape_impl(
+            text.as_ref(),
+            Some(&mut map),
+            &[],
+            false,
+            &mut Scratch::default(),
+            &mut result,
+        );
+        (result, map)
+    }
+
+    /// Reshape the given text into the buffer without allocating, and return the number
+    /// of characters that are written.
+    ///
+    /// It's a `const fn`, so it can also reshape text at compile time. If the buffer is too
+    /// small [`ReshapeError::BufferTooSmall`] is returned with the length that is needed.
+    ///
+    /// The text is reshaped a word at a time on the stack, a word (with the ligatures that
+    /// can go over it) longer than 128 characters returns [`ReshapeError::SegmentTooLong`].
+    #[cfg_attr(
+        feature = "bidi",
+        doc = "The `visual_ordering` option of the config is not applied."
+    )]
+    /// ```rust
+    /// use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
+    ///
+    /// static RESHAPER: ArabicReshaper =
+    ///     ArabicReshaper::new(ReshaperConfig::new(Language::Arabic, LigaturesFlags::default()));
+    ///
+    /// const GREETING: ([char; 8], usize) = {
+    ///     let mut buffer = ['\0'; 8];
+    ///     match RESHAPER.reshape_to_chars("سلام", &mut buffer) {
+    ///         Ok(len) => (buffer, len),
+    ///         Err(_) => panic!("the buffer is too small"),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(GREETING.0[..GREETING.1], ['ﺳ', 'ﻠ', 'ﺎ', 'ﻡ']);
+    /// ```
+    pub const fn reshape_to_chars(
+        &self,
+        text: &str,
+        buffer: &mut [char],
+    ) -> Result<usize, ReshapeError> {
+        segment::reshape(self, text, segment::Buffer::Chars(buffer))
+    }
+
+    /// Reshape the given text into the buffer as UTF-8 without allocating, and return the
+    /// number of bytes that are written. See [`reshape_to_chars`](Self::reshape_to_chars).
+    /// ```rust
+    /// use ar_reshaper::{ArabicReshaper, ReshapeError};
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    /// let mut buffer = [0; 16];
+    ///
+    /// let len = reshaper.reshape_to_bytes("سلام", &mut buffer).unwrap();
+    /// assert_eq!(core::str::from_utf8(&buffer[..len]), Ok("ﺳﻼﻡ"));
+    ///
+    /// assert_eq!(
+    ///     reshaper.reshape_to_bytes("سلام دنیا", &mut buffer),
+    ///     Err(ReshapeError::BufferTooSmall { needed: 22 })
+    /// );
+    /// ```
+    pub const fn reshape_to_bytes(
+        &self,
+        text: &str,
+        buffer: &mut [u8],
+    ) -> Result<usize, ReshapeError> {
+        segment::reshape(self, text, segment::Buffer::Bytes(buffer))
+    }
+
+    /// Reshape the text that has markup in it, like `<b>` or `{0}`, the tokenizer finds the
+    /// tags and placeholders of the markup.
+    ///
+    /// Tags are written as they are and don't break the joining of the letters around them,
+    /// but no ligature is made of the letters on both sides of a tag. Placeholders are
+    /// written as they are too, but they are like a character that doesn't join the letters.
+    /// When `visual_ordering` is enabled, an open and a close tag (like `<b>` and `</b>`)
+    /// are written around the letters that are between them after the text is reordered.
+    /// See the [markup](crate::markup) module for the tokenizers.
+    /// ```rust
+    /// use ar_reshaper::{markup::BBCode, ArabicReshaper};
+    ///
+    /// let reshaper = ArabicReshaper::default();
+    ///
+    /// assert_eq!(reshaper.reshape_markup("[b]س[/b]ب", BBCode), "[b]ﺳ[/b]ﺐ");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reshape_markup<T>(&self, text: &str, mut tokenizer: T) -> String
+    where
+        T: Tokenizer,
+    {
+        let mut result = String::with_capacity(text.len());
+        let tokens = markup::tokens(text, |rest| tokenizer.token(rest));
+        markup::reshape_tokens(self, &tokens, &mut result);
+        result
+    }
+
+    /// Reshape the text, the position of each output character is pushed to the map if there is one.
+    ///
+    /// When it's `strict` the letters that can't be reshaped are returned as an error,
+    /// otherwise they are written as they are. No ligature goes over the byte positions of
+    /// `breaks`. The reshaped text is pushed to the end of `out`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn reshape_impl(
+        &self,
+        text: &str,
+        mut map: Option<&mut Vec<Range<usize>>>,
+        breaks: &[usize],
+        strict: bool,
+        scratch: &mut Scratch,
+        out: &mut String,
+    ) -> Result<(), ReshapeError> {
+        if text.is_empty() {
+            return Ok(());
         }
 
-        let mut result = Vec::with_capacity(text.len());
+        let start = out.len();
+        let map_start = map.as_ref().map_or(0, |map| map.len());
+        out.reserve(text.len());
 
-        if !delete_harakat {
-            if let Some(ph) = position_harakat.get(&-1) {
-                result.extend(ph);
+        let result = segment::reshape_segments(self, text, breaks, scratch, |chars| {
+            out.extend(chars.iter().map(|(c, _)| *c));
+            if let Some(map) = map.as_deref_mut() {
+                map.extend(chars.iter().map(|(_, (start, end))| *start..*end));
             }
+            Ok::<_, Infallible>(())
+        });
+        let Ok(error) = result;
+
+        if let (true, Some(e)) = (strict, error) {
+            out.truncate(start);
+            if let Some(map) = map {
+                map.truncate(map_start);
+            }
+            return Err(e);
+        }
+
+        #[cfg(feature = "bidi")]
+        if self.config.visual_ordering {
+            let reordered = match map {
+                Some(map) => crate::bidi::visual_order_map(&out[start..], map),
+                None => crate::bidi::visual_order(&out[start..]),
+            };
+            out.truncate(start);
+            out.push_str(&reordered);
+        }
+
+        Ok(())
+    }
+
+    /// Reshape the text and write it to `out` as it's reshaped, unless it has to be reordered
+    #[cfg(feature = "alloc")]
+    fn reshape_fmt_impl<W>(&self, text: &str, scratch: &mut Scratch, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        #[cfg(feature = "bidi")]
+        if self.config.visual_ordering {
+            let mut result = String::new();
+            // it can't fail when it's not strict
+            let _ = self.reshape_impl(text, None, &[], false, scratch, &mut result);
+            return out.write_str(&result);
         }
 
-        for (i, (letter, form)) in output.into_iter().enumerate() {
-            if letter != '\0' {
-                result.push(self.letters.get_form(letter, form))
+        segment::reshape_segments(self, text, &[], scratch, |chars| {
+            chars.iter().try_for_each(|(c, _)| out.write_char(*c))
+        })
+        .map(|_| ())
+    }
+
+    /// Turn a reshaped text back into logical Arabic text.
+    ///
+    /// Every presentation form found in the letters of the current language, the built-in
+    /// languages or the ligatures is replaced with its base letters, everything else is kept as is.
+    #[cfg_attr(
+        feature = "bidi",
+        doc = "If `visual_ordering` is enabled in the config the text is also reordered back to logical order first,\n\
+        paragraphs that can be read in both directions (like `hello ﻡﻼﺳ`) are read as right-to-left paragraphs."
+    )]
+    #[cfg(feature = "alloc")]
+    pub fn unshape<S>(&self, text: S) -> String
+    where
+        S: AsRef<str>,
+    {
+        let text = text.as_ref();
+
+        #[cfg(feature = "bidi")]
+        let text = &match self.config.visual_ordering {
+            true => crate::bidi::logical_order(text),
+            false => String::from(text),
+        };
+
+        let letters = self.letters();
+        let mut result = String::with_capacity(text.len());
+
+        for c in text.chars() {
+            // the ZWJs around the letters without forms
+            if c == ZWJ && self.config.forms_fallback == FormsFallback::Zwj {
+                continue;
+            }
+
+            if c.is_ascii() || letters.contains_key(&c) {
+                result.push(c);
+                continue;
             }
 
-            if !delete_harakat {
-                if let Some(ph) = position_harakat.get(&(i as isize)) {
-                    result.extend(ph);
-                }
+            // the letters of the language come first, so the letters that are written with
+            // the same forms (like YEH and FARSI YEH in Urdu) are restored to them
+            let canonical = self.config.language.canonical_letters();
+            let letter = (letters.letters.iter())
+                .find(|(letter, forms)| canonical.contains(letter) && forms.contains(c))
+                .or_else(|| {
+                    [
+                        letters.letters,
+                        &LETTERS_ARABIC,
+                        &LETTERS_ARABIC_V2,
+                        &LETTERS_KURDISH,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .find(|(_, forms)| forms.contains(c))
+                });
+
+            if let Some((letter, _)) = letter {
+                result.push(*letter);
+            } else if let Some(letter) = self.unencoded_letter(c) {
+                result.push(letter);
+            } else if let Some(ligature) = self
+                .config
+                .custom_ligatures
+                .iter()
+                .find(|ligature| ligature.forms.contains(c))
+            {
+                result.push_str(&ligature.text);
+            } else if let Some((pattern, _)) = LIGATURES.iter().find(|(_, forms)| forms.contains(c))
+            {
+                result.extend(pattern.text());
+            } else {
+                result.push(c);
             }
         }
 
-        result.into_iter().collect()
+        result
+    }
+
+    /// Find the letter that is written as the given [`char`] in the Private Use Area
+    #[cfg(feature = "alloc")]
+    fn unencoded_letter(&self, c: char) -> Option<char> {
+        let FormsFallback::PrivateUse(start) = self.config.forms_fallback else {
+            return None;
+        };
+
+        let n = (c as u32).checked_sub(start as u32)? / 4;
+        LETTERS_WITHOUT_FORMS.get(n as usize).copied()
     }
 
     /// Reshape all lines in the given slice and return a new [Vec<String>] of strings
+    #[cfg(feature = "alloc")]
     pub fn reshape_lines<S, L>(&self, lines: L) -> Vec<String>
     where
         S: AsRef<str>,
@@ -241,14 +641,10 @@ impl ArabicReshaper {
     where
         F: FnOnce(&mut ReshaperConfig),
     {
-        let language_before = self.config.language;
-
         func(&mut self.config);
 
-        if language_before != self.config.language {
-            // language changed, update letters
-            self.letters.change_language(self.config.language);
-        }
+        // language might be changed, update the letters index
+        self.letters_index = LettersIndex::for_language(&self.config.language);
     }
 }
 
@@ -0,0 +1,802 @@
+//! The reshaping that every method of [ArabicReshaper] uses, from the `const fn`s that
+//! write to fixed-size buffers to the ones that allocate.
+//!
+//! The text is reshaped in segments. A segment ends before a character when nothing after
+//! it can change the text before it: the last character isn't a letter (so the next letter
+//! doesn't join with it) and no ligature can continue after it. The `const fn`s keep the
+//! segment on the stack, the other methods move it to a [Scratch] when it doesn't fit.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    config::FormsFallback,
+    error::ReshapeError,
+    form::{Forms, LetterForm},
+    letters::{
+        letters_db::{LETTERS_WITHOUT_FORMS, TATWEEL, ZWJ},
+        Letters,
+    },
+    ligatures::{
+        automaton::{next_char, next_position, ROOT},
+        LETTERS_LIGATURES_RANGE, LIGATURES, LIGATURES_AUTOMATON,
+    },
+    reshaper::is_haraka,
+    ArabicReshaper,
+};
+
+/// Maximum number of characters (and harakat) in a segment of the `const fn`s
+const SEGMENT_LEN: usize = 128;
+
+/// Number of characters (and harakat) in a segment that is kept on the stack
+/// before it's moved to the [Scratch]
+#[cfg(feature = "alloc")]
+const STACK_LEN: usize = 32;
+
+/// The buffer that the reshaped text is written to
+pub(crate) enum Buffer<'a> {
+    Chars(&'a mut [char]),
+    Bytes(&'a mut [u8]),
+    /// The characters with the byte range of the text that produced them
+    #[cfg(feature = "alloc")]
+    Mapped(&'a mut [(char, (usize, usize))]),
+}
+
+struct Output<'a> {
+    buffer: Buffer<'a>,
+    /// Length of the reshaped text in chars or bytes, it keeps growing after the
+    /// buffer is full so the needed length can be reported.
+    len: usize,
+    /// The last character that is written
+    last: char,
+    /// The first letter that doesn't have the form it needs
+    error: Option<ReshapeError>,
+}
+
+impl Output<'_> {
+    const fn capacity(&self) -> usize {
+        match &self.buffer {
+            Buffer::Chars(chars) => chars.len(),
+            Buffer::Bytes(bytes) => bytes.len(),
+            #[cfg(feature = "alloc")]
+            Buffer::Mapped(chars) => chars.len(),
+        }
+    }
+
+    /// Push the character, `source` is only kept by [Buffer::Mapped]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    const fn push(&mut self, c: char, source: (usize, usize)) {
+        match &mut self.buffer {
+            Buffer::Chars(chars) => {
+                if self.len < chars.len() {
+                    chars[self.len] = c;
+                }
+                self.len += 1;
+            }
+            Buffer::Bytes(bytes) => {
+                if self.len + c.len_utf8() <= bytes.len() {
+                    c.encode_utf8(bytes.split_at_mut(self.len).1);
+                }
+                self.len += c.len_utf8();
+            }
+            #[cfg(feature = "alloc")]
+            Buffer::Mapped(chars) => {
+                if self.len < chars.len() {
+                    chars[self.len] = (c, source);
+                }
+                self.len += 1;
+            }
+        }
+        self.last = c;
+    }
+}
+
+/// A character of the segment
+#[derive(Clone, Copy)]
+pub(crate) struct Letter {
+    c: char,
+    form: LetterForm,
+    /// The ligature that starts at the letter, `'\0'` when there is none
+    ligature: char,
+    /// The letter is part of a ligature
+    replaced: bool,
+    /// The letter is part of a ligature that can't be replaced again
+    protected: bool,
+    /// Byte range of the letter in the text, a ligature covers all of its letters
+    source: (usize, usize),
+}
+
+impl Letter {
+    const EMPTY: Self = Self {
+        c: '\0',
+        form: LetterForm::Unsupported,
+        ligature: '\0',
+        replaced: false,
+        protected: false,
+        source: (0, 0),
+    };
+}
+
+/// A haraka and the position of the letter it's written after
+#[derive(Clone, Copy)]
+pub(crate) struct Haraka {
+    position: isize,
+    c: char,
+    source: (usize, usize),
+}
+
+impl Haraka {
+    const EMPTY: Self = Self {
+        position: 0,
+        c: '\0',
+        source: (0, 0),
+    };
+}
+
+/// The characters that are reshaped together
+struct Segment<'a> {
+    letters: &'a mut [Letter],
+    len: usize,
+    harakat: &'a mut [Haraka],
+    harakat_len: usize,
+}
+
+const fn same_form(a: LetterForm, b: LetterForm) -> bool {
+    a as usize == b as usize
+}
+
+/// Compare the text with the start of the letters, return the number of
+/// characters that are the same and if all of the text is matched.
+#[cfg(feature = "alloc")]
+const fn common_prefix(text: &str, letters: &[Letter]) -> (usize, bool) {
+    let bytes = text.as_bytes();
+    let (mut pos, mut len) = (0, 0);
+
+    while pos < bytes.len() && len < letters.len() {
+        let (c, next) = next_char(bytes, pos);
+        if c != letters[len].c {
+            return (len, false);
+        }
+        (pos, len) = (next, len + 1);
+    }
+    (len, pos == bytes.len())
+}
+
+/// Number of letters that the ligature pattern matches at the start of the letters
+const fn pattern_len(pattern: &str, letters: &[Letter]) -> Option<usize> {
+    let bytes = pattern.as_bytes();
+    let (mut pos, mut len) = (0, 0);
+
+    while pos < bytes.len() {
+        if len == letters.len() {
+            return None;
+        }
+
+        let (alternatives, next) = next_position(bytes, pos);
+        // alternatives are inside brackets, skip the `[`
+        let mut c_pos = match bytes[pos] {
+            b'[' => pos + 1,
+            _ => pos,
+        };
+        let mut found = false;
+        let mut idx = 0;
+        while idx < alternatives {
+            let (c, c_next) = next_char(bytes, c_pos);
+            found |= c == letters[len].c;
+            c_pos = c_next;
+            idx += 1;
+        }
+
+        if !found {
+            return None;
+        }
+        (pos, len) = (next, len + 1);
+    }
+    Some(len)
+}
+
+impl Segment<'_> {
+    const fn letters(&self) -> &[Letter] {
+        self.letters.split_at(self.len).0
+    }
+
+    /// Check if the text after the segment doesn't change how it's reshaped
+    const fn can_split(&self, reshaper: &ArabicReshaper, letters: &Letters) -> bool {
+        let last = self.letters[self.len - 1].c;
+        if letters.contains_key(&last) || last == ZWJ || last == TATWEEL {
+            return false;
+        }
+        if !reshaper.config().support_ligatures {
+            return true;
+        }
+
+        // a builtin ligature can continue after the segment
+        let mut state = ROOT;
+        let mut idx = 0;
+        while idx < self.len {
+            state = LIGATURES_AUTOMATON.next_state(state, self.letters[idx].c);
+            idx += 1;
+        }
+        if state != ROOT {
+            return false;
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let custom_ligatures = reshaper.config().custom_ligatures.as_slice();
+            let mut k = 0;
+            while k < custom_ligatures.len() {
+                let text = custom_ligatures[k].text.as_str();
+                let mut start = 0;
+                while start < self.len {
+                    let suffix = self.letters().split_at(start).1;
+                    let (len, whole) = common_prefix(text, suffix);
+                    if !whole && len == suffix.len() {
+                        return false;
+                    }
+                    start += 1;
+                }
+                k += 1;
+            }
+        }
+
+        true
+    }
+
+    /// The form of the letter, the letters that are replaced by a ligature have no form
+    const fn form(&self, idx: usize) -> LetterForm {
+        match self.letters[idx].replaced {
+            true => LetterForm::Unsupported,
+            false => self.letters[idx].form,
+        }
+    }
+
+    /// Replace the letters in the range with the ligature if none of them is part of a
+    /// protected ligature (custom, sentences and words) and there is no break between them.
+    const fn replace(
+        &mut self,
+        start: usize,
+        end: usize,
+        forms: &Forms,
+        shaper: &Shaper,
+        protect: bool,
+    ) {
+        let mut idx = start;
+        while idx < end {
+            if self.letters[idx].protected {
+                return;
+            }
+            idx += 1;
+        }
+
+        let (first, last) = (self.letters[start].source.0, self.letters[end - 1].source.0);
+        let mut idx = 0;
+        while idx < shaper.breaks.len() {
+            if shaper.breaks[idx] > first && shaper.breaks[idx] <= last {
+                return;
+            }
+            idx += 1;
+        }
+        let isolated_form = shaper.isolated_form;
+
+        let (a_form, b_form) = (self.form(start), self.form(end - 1));
+        let a_first = same_form(a_form, isolated_form) || same_form(a_form, LetterForm::Initial);
+        let b_last = same_form(b_form, isolated_form) || same_form(b_form, LetterForm::Final);
+
+        // +-----------+----------+---------+---------+----------+
+        // | a   \   b | ISOLATED | INITIAL | MEDIAL  | FINAL    |
+        // +-----------+----------+---------+---------+----------+
+        // | ISOLATED  | ISOLATED | INITIAL | INITIAL | ISOLATED |
+        // | INITIAL   | ISOLATED | INITIAL | INITIAL | ISOLATED |
+        // | MEDIAL    | FINAL    | MEDIAL  | MEDIAL  | FINAL    |
+        // | FINAL     | FINAL    | MEDIAL  | MEDIAL  | FINAL    |
+        // +-----------+----------+---------+---------+----------+
+        let ligature_form = match (a_first, b_last) {
+            (true, true) => LetterForm::Isolated,
+            (true, false) => LetterForm::Initial,
+            (false, true) => LetterForm::Final,
+            (false, false) => LetterForm::Medial,
+        };
+
+        let Some(ligature) = forms.get(ligature_form) else {
+            return;
+        };
+
+        let mut idx = start;
+        while idx < end {
+            self.letters[idx].ligature = '\0';
+            self.letters[idx].replaced = true;
+            self.letters[idx].protected |= protect;
+            idx += 1;
+        }
+        self.letters[start].ligature = ligature;
+        self.letters[start].source.1 = self.letters[end - 1].source.1;
+    }
+
+    /// Check if a whole word ligature in the range has a letter right before or after it
+    const fn inside_word(&self, start: usize, end: usize, letters: &Letters) -> bool {
+        start > 0 && letters.contains_key(&self.letters[start - 1].c)
+            || end < self.len && letters.contains_key(&self.letters[end].c)
+    }
+
+    /// Find the first enabled builtin ligature after `after` that is in the segment
+    const fn next_ligature(
+        &self,
+        reshaper: &ArabicReshaper,
+        letters: &Letters,
+        after: Option<usize>,
+    ) -> Option<usize> {
+        let mut found = None;
+        let mut state = ROOT;
+        let mut idx = 0;
+
+        while idx < self.len {
+            state = LIGATURES_AUTOMATON.next_state(state, self.letters[idx].c);
+
+            let mut matches = LIGATURES_AUTOMATON.matches(state);
+            while let Some((ligature, len)) = LIGATURES_AUTOMATON.next_match(&mut matches) {
+                let after_last = match after {
+                    Some(after) => ligature > after,
+                    None => true,
+                };
+                let first = match found {
+                    Some(found) => ligature < found,
+                    None => true,
+                };
+
+                if after_last
+                    && first
+                    && reshaper.config().ligatures.list[ligature]
+                    && !(LIGATURES[ligature].0.whole_word
+                        && self.inside_word(idx + 1 - len, idx + 1, letters))
+                {
+                    found = Some(ligature);
+                }
+            }
+            idx += 1;
+        }
+
+        found
+    }
+
+    /// Replace the ligatures of the segment one by one in the order of the list (custom >
+    /// sentences > words > letters), and the matches of each ligature from left to right
+    /// without overlapping. Nothing can replace the letters of a custom, sentence or word
+    /// ligature, but a letters ligature can replace the letters of another letters ligature
+    /// (using the forms that are left by it) the same as the old reshaper did.
+    const fn apply_ligatures(&mut self, shaper: &Shaper) {
+        let (reshaper, letters) = (shaper.reshaper, &shaper.letters);
+        #[cfg(feature = "alloc")]
+        {
+            let custom_ligatures = reshaper.config().custom_ligatures.as_slice();
+            let mut k = 0;
+            while k < custom_ligatures.len() {
+                let text = custom_ligatures[k].text.as_str();
+                let mut start = 0;
+                while start < self.len {
+                    let (len, whole) = common_prefix(text, self.letters().split_at(start).1);
+                    if whole && len > 0 {
+                        let forms = &custom_ligatures[k].forms;
+                        self.replace(start, start + len, forms, shaper, true);
+                        start += len;
+                    } else {
+                        start += 1;
+                    }
+                }
+                k += 1;
+            }
+        }
+
+        let mut last = None;
+        while let Some(ligature) = self.next_ligature(reshaper, letters, last) {
+            let (pattern, forms) = &LIGATURES[ligature];
+
+            let mut start = 0;
+            while start < self.len {
+                let mut next = start + 1;
+                let mut word = 0;
+                while word < pattern.matches.len() {
+                    let rest = self.letters().split_at(start).1;
+                    if let Some(len) = pattern_len(pattern.matches[word], rest) {
+                        if !(pattern.whole_word && self.inside_word(start, start + len, letters)) {
+                            let protect = ligature < LETTERS_LIGATURES_RANGE.start;
+                            self.replace(start, start + len, forms, shaper, protect);
+                            next = start + len;
+                            break;
+                        }
+                    }
+                    word += 1;
+                }
+                start = next;
+            }
+
+            last = Some(ligature);
+        }
+    }
+}
+
+/// The reshaper with what it needs for every character
+struct Shaper<'a> {
+    reshaper: &'a ArabicReshaper,
+    letters: Letters<'a>,
+    isolated_form: LetterForm,
+    /// Byte positions of the text that no ligature can go over, letters still join over them
+    breaks: &'a [usize],
+}
+
+impl<'a> Shaper<'a> {
+    const fn new(reshaper: &'a ArabicReshaper, breaks: &'a [usize]) -> Self {
+        Self {
+            reshaper,
+            breaks,
+            letters: reshaper.letters(),
+            isolated_form: match reshaper.config().use_unshaped_instead_of_isolated {
+                true => LetterForm::Unshaped,
+                false => LetterForm::Isolated,
+            },
+        }
+    }
+
+    /// Add the next character of the text to the segment, the segment is written to the
+    /// output first when the character can't change it. Return `false` if the segment is full.
+    const fn push(
+        &self,
+        segment: &mut Segment,
+        c: char,
+        source: (usize, usize),
+        output: &mut Output,
+    ) -> bool {
+        let config = self.reshaper.config();
+        let letters = &self.letters;
+
+        let len = segment.len;
+        let after_letter = len > 0 && letters.contains_key(&segment.letters[len - 1].c);
+        let letter = config.language.normalize(c, after_letter);
+
+        if is_haraka(letter) {
+            if !config.delete_harakat {
+                if segment.harakat_len == segment.harakat.len() {
+                    return false;
+                }
+
+                // harakat at the start of the segment are at the -1 position
+                let mut position = len as isize - 1;
+                if config.shift_harakat_position {
+                    position -= 1;
+                }
+                if position < -1 {
+                    position = -1;
+                }
+
+                segment.harakat[segment.harakat_len] = Haraka {
+                    position,
+                    c: letter,
+                    source,
+                };
+                segment.harakat_len += 1;
+            }
+            return true;
+        }
+        if letter == TATWEEL && config.delete_tatweel || letter == ZWJ && !config.support_zwj {
+            return true;
+        }
+
+        if len > 0 && segment.can_split(self.reshaper, letters) {
+            self.flush(segment, output);
+        } else if len == segment.letters.len() {
+            return false;
+        }
+
+        let len = segment.len;
+        let form = if !letters.contains_key(&letter) {
+            LetterForm::Unsupported
+        } else if len == 0 {
+            self.isolated_form
+        } else {
+            let previous = &mut segment.letters[len - 1];
+
+            if same_form(previous.form, LetterForm::Unsupported)
+                || !letters.connects_with_letter_before(letter)
+                || !letters.connects_with_letter_after(previous.c)
+                || same_form(previous.form, LetterForm::Final)
+                    && !letters.connects_with_letters_before_and_after(previous.c)
+            {
+                self.isolated_form
+            } else {
+                previous.form = match same_form(previous.form, self.isolated_form) {
+                    true => LetterForm::Initial,
+                    false => LetterForm::Medial,
+                };
+                LetterForm::Final
+            }
+        };
+
+        segment.letters[len] = Letter {
+            c: letter,
+            form,
+            source,
+            ..Letter::EMPTY
+        };
+        segment.len += 1;
+
+        // Remove ZWJ if it's the second to last item as it won't be useful
+        if config.support_zwj && len > 0 && segment.letters[len - 1].c == ZWJ {
+            segment.letters[len - 1] = segment.letters[len];
+            segment.len -= 1;
+        }
+
+        true
+    }
+
+    /// Write the rest of the text to the output
+    const fn finish(&self, segment: &mut Segment, output: &mut Output) {
+        let len = segment.len;
+        if self.reshaper.config().support_zwj && len > 0 && segment.letters[len - 1].c == ZWJ {
+            segment.len -= 1;
+        }
+        self.flush(segment, output);
+    }
+
+    /// Write the reshaped segment to the output and clear it
+    const fn flush(&self, segment: &mut Segment, output: &mut Output) {
+        let config = self.reshaper.config();
+        if config.support_ligatures {
+            segment.apply_ligatures(self);
+        }
+
+        let mut next_haraka = 0;
+        if !config.delete_harakat {
+            push_harakat(
+                segment,
+                &mut next_haraka,
+                -1,
+                config.shift_harakat_position,
+                output,
+            );
+        }
+
+        let mut idx = 0;
+        while idx < segment.len {
+            let letter = segment.letters[idx];
+            if letter.replaced {
+                if letter.ligature != '\0' {
+                    output.push(letter.ligature, letter.source);
+                }
+            } else if letter.c != '\0' {
+                self.push_form(output, letter.c, letter.form, letter.source);
+            }
+
+            if !config.delete_harakat {
+                let shift = config.shift_harakat_position;
+                push_harakat(segment, &mut next_haraka, idx as isize, shift, output);
+            }
+            idx += 1;
+        }
+
+        (segment.len, segment.harakat_len) = (0, 0);
+    }
+
+    /// Push the given form of the letter, letters without presentation forms are
+    /// written using the [FormsFallback] of the config. Letters that can't be
+    /// reshaped are written as they are and the first one is kept as an error.
+    const fn push_form(
+        &self,
+        output: &mut Output,
+        letter: char,
+        form: LetterForm,
+        source: (usize, usize),
+    ) {
+        let c = match self.letters.get_form(letter, form, source.0) {
+            Ok(c) => c,
+            Err(e) => {
+                if output.error.is_none() {
+                    output.error = Some(e);
+                }
+                output.push(letter, source);
+                return;
+            }
+        };
+
+        let mut n = 0;
+        while n < LETTERS_WITHOUT_FORMS.len() && LETTERS_WITHOUT_FORMS[n] != letter {
+            n += 1;
+        }
+
+        // only the letters without presentation forms need a fallback, a form that is
+        // the letter itself (like TATWEEL) is written as it is
+        if c != letter
+            || matches!(form, LetterForm::Unshaped | LetterForm::Unsupported)
+            || n == LETTERS_WITHOUT_FORMS.len()
+        {
+            output.push(c, source);
+            return;
+        }
+
+        match self.reshaper.config().forms_fallback {
+            FormsFallback::Base => output.push(letter, source),
+            FormsFallback::Zwj => {
+                // two letters without forms next to each other share the same ZWJ
+                if matches!(form, LetterForm::Medial | LetterForm::Final) && output.last != ZWJ {
+                    output.push(ZWJ, source);
+                }
+                output.push(letter, source);
+                if matches!(form, LetterForm::Initial | LetterForm::Medial) {
+                    output.push(ZWJ, source);
+                }
+            }
+            FormsFallback::PrivateUse(start) => {
+                let c = char::from_u32(start as u32 + 4 * n as u32 + form as u32);
+                let c = match c {
+                    Some(c) => c,
+                    None => letter,
+                };
+                output.push(c, source);
+            }
+        }
+    }
+}
+
+/// Push the harakat of the given position, shifted harakat are written in reverse
+const fn push_harakat(
+    segment: &Segment,
+    next_haraka: &mut usize,
+    position: isize,
+    shift: bool,
+    output: &mut Output,
+) {
+    let group_start = *next_haraka;
+    while *next_haraka < segment.harakat_len && segment.harakat[*next_haraka].position == position {
+        *next_haraka += 1;
+    }
+
+    let mut k = 0;
+    while k < *next_haraka - group_start {
+        let idx = match shift {
+            true => *next_haraka - 1 - k,
+            false => group_start + k,
+        };
+        output.push(segment.harakat[idx].c, segment.harakat[idx].source);
+        k += 1;
+    }
+}
+
+/// Reshape the text into the buffer and return the length of the reshaped text
+pub(crate) const fn reshape(
+    reshaper: &ArabicReshaper,
+    text: &str,
+    buffer: Buffer,
+) -> Result<usize, ReshapeError> {
+    let shaper = Shaper::new(reshaper, &[]);
+    let mut letters = [Letter::EMPTY; SEGMENT_LEN];
+    let mut harakat = [Haraka::EMPTY; SEGMENT_LEN];
+    let mut segment = Segment {
+        letters: &mut letters,
+        len: 0,
+        harakat: &mut harakat,
+        harakat_len: 0,
+    };
+    let mut output = Output {
+        buffer,
+        len: 0,
+        last: '\0',
+        error: None,
+    };
+
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let (c, next) = next_char(bytes, pos);
+        if !shaper.push(&mut segment, c, (pos, next), &mut output) {
+            return Err(ReshapeError::SegmentTooLong { position: pos });
+        }
+        pos = next;
+    }
+    shaper.finish(&mut segment, &mut output);
+
+    match output.len <= output.capacity() {
+        true => Ok(output.len),
+        false => Err(ReshapeError::BufferTooSmall { needed: output.len }),
+    }
+}
+
+/// The vectors that a segment is moved to when it doesn't fit on the stack, kept
+/// in the [ReshapeBuffer](crate::ReshapeBuffer) to be reused.
+#[derive(Clone, Default)]
+#[cfg(feature = "alloc")]
+pub(crate) struct Scratch {
+    letters: Vec<Letter>,
+    harakat: Vec<Haraka>,
+    output: Vec<(char, (usize, usize))>,
+}
+
+/// Reshape the text and call `write` with the reshaped characters of every segment and
+/// the byte range of the text that produced them. No ligature goes over the byte
+/// positions of `breaks`. The text is reshaped to the end even if some letters can't be
+/// reshaped, and the first of them is returned.
+#[cfg(feature = "alloc")]
+pub(crate) fn reshape_segments<E>(
+    reshaper: &ArabicReshaper,
+    text: &str,
+    breaks: &[usize],
+    scratch: &mut Scratch,
+    mut write: impl FnMut(&[(char, (usize, usize))]) -> Result<(), E>,
+) -> Result<Option<ReshapeError>, E> {
+    let shaper = Shaper::new(reshaper, breaks);
+    let mut stack_letters = [Letter::EMPTY; STACK_LEN];
+    let mut stack_harakat = [Haraka::EMPTY; STACK_LEN];
+    // a letter is written as 3 characters at most, with the ZWJs around it
+    let mut stack_output = [('\0', (0, 0)); 4 * STACK_LEN];
+    let mut on_stack = true;
+    let (mut len, mut harakat_len, mut last, mut error) = (0, 0, '\0', None);
+
+    let chars = text.char_indices();
+    // `None` is the end of the text
+    for next in chars.map(|(pos, c)| Some((c, pos))).chain([None]) {
+        loop {
+            let (letters, harakat, chars) = match on_stack {
+                true => (
+                    &mut stack_letters[..],
+                    &mut stack_harakat[..],
+                    &mut stack_output[..],
+                ),
+                false => (
+                    &mut scratch.letters[..],
+                    &mut scratch.harakat[..],
+                    &mut scratch.output[..],
+                ),
+            };
+            let capacity = letters.len();
+            let mut segment = Segment {
+                letters,
+                len,
+                harakat,
+                harakat_len,
+            };
+            let mut output = Output {
+                buffer: Buffer::Mapped(chars),
+                len: 0,
+                last,
+                error,
+            };
+
+            let pushed = match next {
+                Some((c, pos)) => {
+                    shaper.push(&mut segment, c, (pos, pos + c.len_utf8()), &mut output)
+                }
+                None => {
+                    shaper.finish(&mut segment, &mut output);
+                    true
+                }
+            };
+            (len, harakat_len) = (segment.len, segment.harakat_len);
+            (last, error) = (output.last, output.error);
+
+            if output.len > 0 {
+                let written = output.len;
+                if let Buffer::Mapped(chars) = output.buffer {
+                    write(&chars[..written])?;
+                }
+            }
+            if pushed {
+                break;
+            }
+
+            // move the segment to the scratch, or make more room in it
+            if on_stack {
+                scratch.letters.clear();
+                scratch.letters.extend_from_slice(&stack_letters[..len]);
+                scratch.harakat.clear();
+                scratch
+                    .harakat
+                    .extend_from_slice(&stack_harakat[..harakat_len]);
+                on_stack = false;
+            }
+            let capacity = scratch.letters.len().max(2 * capacity);
+            scratch.letters.resize(capacity, Letter::EMPTY);
+            scratch.harakat.resize(capacity, Haraka::EMPTY);
+            scratch.output.resize(4 * capacity, ('\0', (0, 0)));
+        }
+    }
+
+    Ok(error)
+}
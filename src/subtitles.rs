@@ -0,0 +1,185 @@
+//! Subtitles
+//!
+//! Reshape the dialogue of SRT, WebVTT and ASS/SSA subtitles, the numbers, timings, cue
+//! settings, styles and every other part of the file are written back as they are.
+//! Tags inside the dialogue (like `<i>` or `{\i1}`) are kept at the same place in the
+//! text and the letters around them are still joined, but they don't make a ligature.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    markup::{angle_tag, entity_len, reshape_tokens, tokens, Token},
+    reshaper::split_terminator,
+    ArabicReshaper,
+};
+
+/// Supported subtitle formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    /// SubRip (`.srt`)
+    Srt,
+    /// Web Video Text Tracks (`.vtt`)
+    WebVtt,
+    /// Advanced SubStation Alpha and SubStation Alpha (`.ass` and `.ssa`)
+    Ass,
+}
+
+impl SubtitleFormat {
+    /// Find the format from the extension of the file, like `srt` or `vtt`
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "srt" => Some(Self::Srt),
+            "vtt" => Some(Self::WebVtt),
+            "ass" | "ssa" => Some(Self::Ass),
+            _ => None,
+        }
+    }
+
+    /// Find the format from the content of the file
+    pub fn detect(text: &str) -> Option<Self> {
+        let text = text.trim_start_matches('\u{FEFF}').trim_start();
+
+        if text.starts_with("WEBVTT") {
+            Some(Self::WebVtt)
+        } else if text.starts_with("[Script Info]") {
+            Some(Self::Ass)
+        } else if text.lines().take(3).any(|line| line.contains("-->")) {
+            Some(Self::Srt)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reshape the dialogue of the subtitles and return the whole file.
+/// ```rust
+/// use ar_reshaper::{
+///     subtitles::{reshape_subtitles, SubtitleFormat},
+///     ArabicReshaper,
+/// };
+///
+/// let srt = "1\n00:00:01,000 --> 00:00:02,000\n<i>سلام</i>\n";
+/// let reshaper = ArabicReshaper::default();
+///
+/// assert_eq!(
+///     reshape_subtitles(&reshaper, srt, SubtitleFormat::Srt),
+///     "1\n00:00:01,000 --> 00:00:02,000\n<i>ﺳﻼﻡ</i>\n"
+/// );
+/// ```
+pub fn reshape_subtitles(reshaper: &ArabicReshaper, text: &str, format: SubtitleFormat) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    match format {
+        SubtitleFormat::Srt => reshape_cues(reshaper, text, false, &mut result),
+        SubtitleFormat::WebVtt => reshape_cues(reshaper, text, true, &mut result),
+        SubtitleFormat::Ass => reshape_ass(reshaper, text, &mut result),
+    }
+
+    result
+}
+
+/// Reshape the text of the SRT or WebVTT cues, the text of a cue is the lines
+/// after the timing line until an empty line.
+fn reshape_cues(reshaper: &ArabicReshaper, text: &str, webvtt: bool, result: &mut String) {
+    // the WebVTT header, NOTE, STYLE and REGION blocks don't have any dialogue
+    let mut skip_block = webvtt;
+    let mut block_start = true;
+    let mut in_text = false;
+
+    for line in text.split_inclusive('\n') {
+        let (content, terminator) = split_terminator(line);
+
+        if content.trim().is_empty() {
+            (skip_block, block_start, in_text) = (false, true, false);
+            result.push_str(line);
+            continue;
+        }
+
+        let first_line = core::mem::replace(&mut block_start, false);
+        if in_text {
+            reshape_tokens(reshaper, &html_tokens(content), result);
+            result.push_str(terminator);
+            continue;
+        }
+
+        if first_line && webvtt && is_webvtt_block(content) {
+            skip_block = true;
+        } else if !skip_block && content.contains("-->") {
+            in_text = true;
+        }
+        result.push_str(line);
+    }
+}
+
+/// Check if the first line of a WebVTT block is the start of a block that isn't a cue
+fn is_webvtt_block(line: &str) -> bool {
+    ["NOTE", "STYLE", "REGION"].iter().any(|name| {
+        line.strip_prefix(name)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+    })
+}
+
+/// Reshape the text of the `Dialogue` lines in the `[Events]` section
+fn reshape_ass(reshaper: &ArabicReshaper, text: &str, result: &mut String) {
+    let mut in_events = false;
+    // the text is the last field, it's the tenth field when there is no `Format` line
+    let mut fields = 10;
+
+    for line in text.split_inclusive('\n') {
+        let (content, terminator) = split_terminator(line);
+        let trimmed = content.trim_start();
+
+        if trimmed.starts_with('[') {
+            in_events = trimmed.trim_end().eq_ignore_ascii_case("[Events]");
+        } else if let (true, Some(format)) = (in_events, trimmed.strip_prefix("Format:")) {
+            fields = format.split(',').count();
+        } else if let (true, Some(dialogue)) = (in_events, trimmed.strip_prefix("Dialogue:")) {
+            // the text can have commas in it, so only the fields before it are split
+            let text_start = dialogue
+                .match_indices(',')
+                .nth(fields.saturating_sub(2))
+                .map(|(idx, _)| idx + 1);
+
+            if let Some(text_start) = text_start {
+                let start = content.len() - dialogue.len() + text_start;
+                result.push_str(&content[..start]);
+                reshape_tokens(reshaper, &ass_tokens(&content[start..]), result);
+                result.push_str(terminator);
+                continue;
+            }
+        }
+
+        result.push_str(line);
+    }
+}
+
+/// Split the text to the HTML like tags (`<i>`, `<c.yellow>`, `<00:00:01.000>`), the
+/// override blocks (`{\an8}`) that are used in SRT files and the entities (`&amp;`).
+/// A `<` or `{` that doesn't start a tag, like in `a < b`, is a part of the text.
+fn html_tokens(text: &str) -> Vec<Token<'_>> {
+    tokens(text, |rest| match rest.as_bytes() {
+        [b'<', b'0'..=b'9', ..] => {
+            let len = rest.find(|c: char| !c.is_ascii_digit() && c != ':' && c != '.')?;
+            (rest.as_bytes()[len] == b'>').then(|| (Token::Tag(&rest[..=len]), len + 1))
+        }
+        [b'<', ..] => angle_tag(rest),
+        [b'{', b'\\', ..] => rest
+            .find('}')
+            .map(|end| (Token::Tag(&rest[..=end]), end + 1)),
+        [b'&', ..] => entity_len(rest).map(|len| (Token::Placeholder(&rest[..len]), len)),
+        _ => None,
+    })
+}
+
+/// Split the text to the override blocks (`{\i1}`), the line breaks (`\N` and `\n`)
+/// and the hard spaces (`\h`) of ASS.
+fn ass_tokens(text: &str) -> Vec<Token<'_>> {
+    tokens(text, |rest| match rest.as_bytes() {
+        [b'{', ..] => rest
+            .find('}')
+            .map(|end| (Token::Tag(&rest[..=end]), end + 1)),
+        [b'\\', b'N' | b'n', ..] => Some((Token::Break(&rest[..2]), 2)),
+        [b'\\', b'h', ..] => Some((Token::Placeholder(&rest[..2]), 2)),
+        _ => None,
+    })
+}
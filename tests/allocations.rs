@@ -0,0 +1,61 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use ar_reshaper::{ArabicReshaper, ReshapeBuffer};
+
+/// Count the allocations of the current thread, so the other tests don't change it
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let start = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - start
+}
+
+#[test]
+fn reshaping_without_allocating() {
+    let reshaper = ArabicReshaper::default();
+    let text = "السَلَاْمٌ عَلَيْكُمْ، الله أكبر";
+    let long_word = "ب".repeat(100);
+    let mut out = String::with_capacity(1024);
+
+    assert_eq!(allocations(|| reshaper.reshape_into(text, &mut out)), 0);
+    out.clear();
+    assert_eq!(
+        allocations(|| reshaper.reshape_fmt(text, &mut out).unwrap()),
+        0
+    );
+    assert_eq!(out, reshaper.reshape(text));
+
+    // words that don't fit on the stack use the memory of the buffer
+    let mut buffer = ReshapeBuffer::new();
+    buffer.reshape(&reshaper, &long_word);
+    out.clear();
+    assert_eq!(
+        allocations(|| buffer.reshape_fmt(&reshaper, &long_word, &mut out).unwrap()),
+        0
+    );
+    assert_eq!(out, reshaper.reshape(&long_word));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
 
 // we can't create a reshaper fully const!
@@ -32,3 +34,80 @@ fn default_reshaping() {
         assert_eq!(RESHAPER.reshape(before), after);
     }
 }
+
+#[test]
+fn custom_language_reshaping() {
+    use ar_reshaper::form::Forms;
+
+    static LETTERS: [(char, Forms); 3] = [
+        ('ب', Forms::new('1', '2', '3', '4')),
+        ('ا', Forms::new('5', '\0', '\0', '6')),
+        // a letter outside of the arabic block
+        ('\u{0750}', Forms::new('7', '8', '9', '0')),
+    ];
+
+    let reshaper = ArabicReshaper::new(ReshaperConfig::new(
+        Language::Custom(&LETTERS),
+        LigaturesFlags::none(),
+    ));
+
+    let cases = [
+        ("ب", "1"),
+        ("ببب", "234"),
+        ("با", "26"),
+        ("\u{0750}ب\u{0750}", "830"),
+        ("سب", "س1"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+    }
+}
+
+#[test]
+fn custom_table_reshaping() {
+    use ar_reshaper::{form::Forms, letters::LetterTable};
+
+    let table: LetterTable = [
+        ('ب', Forms::new('1', '2', '3', '4')),
+        ('ا', Forms::new('5', '\0', '\0', '6')),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut reshaper = ArabicReshaper::default();
+    assert_eq!(reshaper.reshape("با"), "ﺑﺎ");
+
+    reshaper.modify_config(|c| c.language = Language::CustomTable(table.clone()));
+    assert_eq!(reshaper.reshape("با"), "26");
+    assert_eq!(reshaper.reshape("ببب"), "234");
+
+    reshaper.modify_config(|c| {
+        c.language = Language::CustomTable(table.with_letter('ب', Forms::new('7', '8', '9', '0')))
+    });
+    assert_eq!(reshaper.reshape("با"), "86");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn custom_table_serde() {
+    use ar_reshaper::{
+        form::Forms,
+        letters::{letters_db::LETTERS_ARABIC, LetterTable},
+    };
+
+    let table = LetterTable::from(&LETTERS_ARABIC[..]).with_letter(
+        '\u{0750}',
+        Forms::new('\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}'),
+    );
+    let config = ReshaperConfig::new(Language::CustomTable(table), LigaturesFlags::none());
+
+    let json = serde_json::to_string(&config).unwrap();
+    let config: ReshaperConfig = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(config.language.letters().len(), LETTERS_ARABIC.len() + 1);
+    assert_eq!(
+        ArabicReshaper::new(config).reshape("\u{0750}\u{0750}ب"),
+        "\u{E001}\u{E002}ﺐ"
+    );
+}
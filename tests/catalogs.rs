@@ -0,0 +1,175 @@
+#![cfg(feature = "catalogs")]
+
+use ar_reshaper::{
+    catalogs::{CatalogFormat, CatalogProcessor},
+    ReshaperConfig,
+};
+
+fn processor() -> CatalogProcessor {
+    CatalogProcessor::new(ReshaperConfig::default())
+}
+
+#[test]
+fn po() {
+    let po = "# Persian translation\n\
+              msgid \"\"\n\
+              msgstr \"\"\n\
+              \"Language: fa\\n\"\n\
+              \n\
+              #: src/main.rs:10\n\
+              msgctxt \"greeting\"\n\
+              msgid \"Hello %s\"\n\
+              msgstr \"سلام %s\"\n\
+              \n\
+              msgid \"One book\"\n\
+              msgid_plural \"%d books\"\n\
+              msgstr[0] \"یک کتاب\"\n\
+              msgstr[1] \"\"\n\
+              \"%d کت\"\n\
+              \"اب\\n\\\"نو\\\"\"\n\
+              \n\
+              #~ msgid \"Old\"\n\
+              #~ msgstr \"قدیمی\"\n";
+
+    // the strings on the next lines are joined to the strings before them
+    let expected = "# Persian translation\n\
+                    msgid \"\"\n\
+                    msgstr \"\"\n\
+                    \"Language: fa\\n\"\n\
+                    \n\
+                    #: src/main.rs:10\n\
+                    msgctxt \"greeting\"\n\
+                    msgid \"Hello %s\"\n\
+                    msgstr \"ﺳﻼﻡ %s\"\n\
+                    \n\
+                    msgid \"One book\"\n\
+                    msgid_plural \"%d books\"\n\
+                    msgstr[0] \"ﯾﮏ ﮐﺘﺎﺏ\"\n\
+                    msgstr[1] \"\"\n\
+                    \"%d ﮐﺘ\"\n\
+                    \"ﺎﺏ\\n\\\"ﻧﻮ\\\"\"\n\
+                    \n\
+                    #~ msgid \"Old\"\n\
+                    #~ msgstr \"قدیمی\"\n";
+
+    assert_eq!(CatalogFormat::detect(po), Some(CatalogFormat::Po));
+    assert_eq!(processor().reshape(po, CatalogFormat::Po), expected);
+}
+
+#[test]
+fn po_without_terminator() {
+    assert_eq!(
+        processor().reshape("msgid \"a\"\r\nmsgstr \"سلام\"", CatalogFormat::Po),
+        "msgid \"a\"\r\nmsgstr \"ﺳﻼﻡ\""
+    );
+}
+
+#[test]
+fn placeholders() {
+    let po = "msgid \"a\"\n\
+              msgstr \"%1$sسلام%-5.2f {name}من%% {count, plural, one {# کتاب} other {{count} کتاب}}\"\n";
+
+    // placeholders don't join the letters around them
+    let expected = "msgid \"a\"\n\
+                    msgstr \"%1$sﺳﻼﻡ%-5.2f {name}ﻣﻦ%% {count, plural, one {# ﮐﺘﺎﺏ} other {{count} ﮐﺘﺎﺏ}}\"\n";
+
+    assert_eq!(processor().reshape(po, CatalogFormat::Po), expected);
+}
+
+#[test]
+fn android_strings() {
+    let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+               <resources xmlns:xliff=\"urn:oasis:names:tc:xliff:document:1.2\">\n\
+               <!-- <string name=\"old\">سلام</string> -->\n\
+               <string name=\"hello\">سلام <xliff:g id=\"name\" example=\"علی\">%1$s</xliff:g>\\nدنیا</string>\n\
+               <string name=\"key\" translatable=\"false\">سلام</string>\n\
+               <string name=\"html\"><![CDATA[<b>سل</b>ام]]> &amp; من\\'</string>\n\
+               <plurals name=\"books\">\n\
+               <item quantity=\"one\">%d کتاب</item>\n\
+               </plurals>\n\
+               <string-array name=\"days\" translatable=\"false\">\n\
+               <item>شنبه</item>\n\
+               </string-array>\n\
+               <style name=\"Theme\"><item name=\"title\">سلام</item></style>\n\
+               </resources>\n";
+
+    let expected = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+                    <resources xmlns:xliff=\"urn:oasis:names:tc:xliff:document:1.2\">\n\
+                    <!-- <string name=\"old\">سلام</string> -->\n\
+                    <string name=\"hello\">ﺳﻼﻡ <xliff:g id=\"name\" example=\"علی\">%1$s</xliff:g>\\nﺩﻧﯿﺎ</string>\n\
+                    <string name=\"key\" translatable=\"false\">سلام</string>\n\
+                    <string name=\"html\"><![CDATA[<b>ﺳﻠ</b>ﺎﻡ]]> &amp; ﻣﻦ\\'</string>\n\
+                    <plurals name=\"books\">\n\
+                    <item quantity=\"one\">%d ﮐﺘﺎﺏ</item>\n\
+                    </plurals>\n\
+                    <string-array name=\"days\" translatable=\"false\">\n\
+                    <item>شنبه</item>\n\
+                    </string-array>\n\
+                    <style name=\"Theme\"><item name=\"title\">سلام</item></style>\n\
+                    </resources>\n";
+
+    assert_eq!(
+        CatalogFormat::detect(xml),
+        Some(CatalogFormat::AndroidStrings)
+    );
+    assert_eq!(
+        processor().reshape(xml, CatalogFormat::AndroidStrings),
+        expected
+    );
+}
+
+#[test]
+fn xliff_1_2() {
+    let xliff = "<xliff version=\"1.2\"><file source-language=\"en\" target-language=\"fa\"><body>\n\
+                 <trans-unit id=\"1\">\n\
+                 <source>Hello <g id=\"1\">world</g></source>\n\
+                 <target state=\"translated\">سلام <g id=\"1\">دن</g>یا<x id=\"2\"/><ph id=\"3\">&lt;br/&gt;</ph>من</target>\n\
+                 <note>سلام</note>\n\
+                 </trans-unit>\n\
+                 </body></file></xliff>";
+
+    let expected = "<xliff version=\"1.2\"><file source-language=\"en\" target-language=\"fa\"><body>\n\
+                    <trans-unit id=\"1\">\n\
+                    <source>Hello <g id=\"1\">world</g></source>\n\
+                    <target state=\"translated\">ﺳﻼﻡ <g id=\"1\">ﺩﻧ</g>ﯿﺎ<x id=\"2\"/><ph id=\"3\">&lt;br/&gt;</ph>ﻣﻦ</target>\n\
+                    <note>سلام</note>\n\
+                    </trans-unit>\n\
+                    </body></file></xliff>";
+
+    assert_eq!(CatalogFormat::detect(xliff), Some(CatalogFormat::Xliff));
+    assert_eq!(processor().reshape(xliff, CatalogFormat::Xliff), expected);
+}
+
+#[test]
+fn xliff_2_0() {
+    let xliff = "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" srcLang=\"en\" trgLang=\"ar\">\n\
+                 <file id=\"f1\"><unit id=\"u1\"><segment>\n\
+                 <source>Hi <pc id=\"1\">there</pc><ph id=\"2\"/></source>\n\
+                 <target>مر<pc id=\"1\">حبا</pc><ph id=\"2\"/>{user}</target>\n\
+                 </segment></unit></file></xliff>";
+
+    let expected = "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" srcLang=\"en\" trgLang=\"ar\">\n\
+                    <file id=\"f1\"><unit id=\"u1\"><segment>\n\
+                    <source>Hi <pc id=\"1\">there</pc><ph id=\"2\"/></source>\n\
+                    <target>ﻣﺮ<pc id=\"1\">ﺣﺒﺎ</pc><ph id=\"2\"/>{user}</target>\n\
+                    </segment></unit></file></xliff>";
+
+    assert_eq!(processor().reshape(xliff, CatalogFormat::Xliff), expected);
+}
+
+#[test]
+fn from_extension() {
+    assert_eq!(
+        CatalogFormat::from_extension("pot"),
+        Some(CatalogFormat::Po)
+    );
+    assert_eq!(
+        CatalogFormat::from_extension("XML"),
+        Some(CatalogFormat::AndroidStrings)
+    );
+    assert_eq!(
+        CatalogFormat::from_extension("xlf"),
+        Some(CatalogFormat::Xliff)
+    );
+    assert_eq!(CatalogFormat::from_extension("srt"), None);
+}
@@ -0,0 +1,283 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use ar_reshaper::{
+    config::{FormsFallback, LigaturesFlags},
+    ArabicReshaper, Language, ReshaperConfig,
+};
+
+const TEXT: &str = "السَلَام عليكم\r\nلا إله إلا الله\n";
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ar-reshaper"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // the tool can exit before reading the input (like for an invalid font)
+    match child.stdin.take().unwrap().write_all(stdin.as_bytes()) {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => {}
+        result => result.unwrap(),
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], stdin: &str) -> String {
+    let output = run(args, stdin);
+    assert!(output.status.success(), "{args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn reshape(config: ReshaperConfig, text: &str) -> String {
+    let reshaper = ArabicReshaper::new(config);
+    text.split_inclusive('\n')
+        .map(|line| {
+            let content = line.trim_end_matches(['\r', '\n']);
+            reshaper.reshape(content) + &line[content.len()..]
+        })
+        .collect()
+}
+
+/// A file in the temp directory that is removed when it's dropped
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, content: &[u8]) -> Self {
+        let path = std::env::temp_dir().join(format!("ar-reshaper-{}-{name}", std::process::id()));
+        fs::write(&path, content).unwrap();
+        Self(path)
+    }
+
+    fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn stdin_to_stdout() {
+    assert_eq!(stdout(&[], TEXT), reshape(ReshaperConfig::default(), TEXT));
+    assert_eq!(
+        stdout(&["-"], TEXT),
+        reshape(ReshaperConfig::default(), TEXT)
+    );
+}
+
+#[test]
+fn files_to_stdout() {
+    let first = TempFile::new("first.txt", "سلام\n".as_bytes());
+    let second = TempFile::new("second.txt", "دنیا\n".as_bytes());
+
+    assert_eq!(stdout(&[first.path(), second.path()], ""), "ﺳﻼﻡ\nﺩﻧﯿﺎ\n");
+    // the standard input can be used between the files
+    assert_eq!(
+        stdout(&[first.path(), "-", second.path()], "و\n"),
+        "ﺳﻼﻡ\nﻭ\nﺩﻧﯿﺎ\n"
+    );
+
+    // the last line of a file doesn't join the first line of the next file
+    let first = TempFile::new("first-no-newline.txt", "سلام".as_bytes());
+    let second = TempFile::new("second-no-newline.txt", "دنیا".as_bytes());
+    assert_eq!(stdout(&[first.path(), second.path()], ""), "ﺳﻼﻡﺩﻧﯿﺎ");
+}
+
+#[test]
+fn in_place() {
+    let file = TempFile::new("in-place.txt", TEXT.as_bytes());
+
+    assert_eq!(stdout(&["--in-place", file.path()], ""), "");
+    assert_eq!(
+        fs::read_to_string(&file.0).unwrap(),
+        reshape(ReshaperConfig::default(), TEXT)
+    );
+
+    // the standard input can't be changed in place
+    assert_eq!(run(&["-i", "-"], "").status.code(), Some(2));
+    assert_eq!(run(&["-i"], "").status.code(), Some(2));
+}
+
+#[test]
+fn config_flags() {
+    let cases: [(&[&str], ReshaperConfig); 9] = [
+        (
+            &["--language", "urdu"],
+            ReshaperConfig::new(Language::Urdu, LigaturesFlags::default()),
+        ),
+        (
+            &["--keep-harakat"],
+            ReshaperConfig {
+                delete_harakat: false,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--keep-harakat", "--shift-harakat"],
+            ReshaperConfig {
+                delete_harakat: false,
+                shift_harakat_position: true,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--delete-tatweel"],
+            ReshaperConfig {
+                delete_tatweel: true,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--no-zwj"],
+            ReshaperConfig {
+                support_zwj: false,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--unshaped-isolated"],
+            ReshaperConfig {
+                use_unshaped_instead_of_isolated: true,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--language=arabic-v2", "--ligatures=all"],
+            ReshaperConfig::new(Language::ArabicV2, LigaturesFlags::all()),
+        ),
+        (
+            &["--language=pashto", "--forms-fallback=zwj"],
+            ReshaperConfig {
+                language: Language::Pashto,
+                forms_fallback: FormsFallback::Zwj,
+                ..Default::default()
+            },
+        ),
+        (
+            &["--visual-order"],
+            ReshaperConfig {
+                visual_ordering: true,
+                ..Default::default()
+            },
+        ),
+    ];
+
+    let text = "السَلَام ـــ\u{200D}ب ه\nلا إله إلا الله (ټکي) 123\n";
+    for (args, config) in cases {
+        assert_eq!(stdout(args, text), reshape(config, text), "{args:?}");
+    }
+}
+
+#[test]
+fn ligatures() {
+    assert_eq!(stdout(&["--ligatures=none"], "لا\n"), "ﻟﺎ\n");
+    assert_eq!(stdout(&["--ligatures", "default"], "لا\n"), "ﻻ\n");
+
+    let mut config = ReshaperConfig::new(Language::Arabic, LigaturesFlags::none());
+    config.update_ligature(
+        ar_reshaper::prelude::LigatureNames::ARABIC_LIGATURE_ALLAH,
+        true,
+    );
+    assert_eq!(
+        stdout(&["--ligatures=arabic_ligature_allah"], TEXT),
+        reshape(config, TEXT)
+    );
+
+    let output = run(&["--ligatures=NOT_A_LIGATURE"], TEXT);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("NOT_A_LIGATURE"));
+}
+
+#[test]
+fn config_file() {
+    let config = ReshaperConfig {
+        delete_harakat: false,
+        ..ReshaperConfig::new(Language::Kurdish, LigaturesFlags::all())
+    };
+    let file = TempFile::new(
+        "config.json",
+        serde_json::to_string(&config).unwrap().as_bytes(),
+    );
+
+    assert_eq!(
+        stdout(&["--config", file.path()], TEXT),
+        reshape(config.clone(), TEXT)
+    );
+
+    // the options are applied on top of the config file
+    let expected = reshape(
+        ReshaperConfig {
+            shift_harakat_position: true,
+            ..config
+        },
+        TEXT,
+    );
+    assert_eq!(
+        stdout(&["--config", file.path(), "--shift-harakat"], TEXT),
+        expected
+    );
+
+    let invalid = TempFile::new("invalid.json", b"{");
+    assert_eq!(
+        run(&["--config", invalid.path()], TEXT).status.code(),
+        Some(1)
+    );
+}
+
+#[test]
+fn font() {
+    let invalid = TempFile::new("invalid.ttf", b"not a font");
+
+    let output = run(&["--font", invalid.path()], TEXT);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid font"));
+
+    // a font and a config file can't be used together
+    assert_eq!(
+        run(
+            &["--font", invalid.path(), "--config", invalid.path()],
+            TEXT
+        )
+        .status
+        .code(),
+        Some(2)
+    );
+}
+
+#[test]
+fn exit_codes() {
+    assert_eq!(run(&["--help"], "").status.code(), Some(0));
+    assert_eq!(run(&["--version"], "").status.code(), Some(0));
+
+    // invalid options
+    assert_eq!(run(&["--bogus"], "").status.code(), Some(2));
+    assert_eq!(run(&["--language=latin"], "").status.code(), Some(2));
+    assert_eq!(run(&["--language"], "").status.code(), Some(2));
+    assert_eq!(run(&["--keep-harakat=yes"], "").status.code(), Some(2));
+
+    // files that can't be reshaped
+    assert_eq!(
+        run(&["/this/file/does/not/exist"], "").status.code(),
+        Some(1)
+    );
+    let invalid = TempFile::new("invalid.txt", b"\xFF\xFE\n");
+    let output = run(&[invalid.path()], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("UTF-8"));
+
+    // the file is not changed when it can't be reshaped
+    assert_eq!(run(&["-i", invalid.path()], "").status.code(), Some(1));
+    assert_eq!(fs::read(&invalid.0).unwrap(), b"\xFF\xFE\n");
+}
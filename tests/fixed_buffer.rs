@@ -0,0 +1,146 @@
+#[cfg(feature = "alloc")]
+use ar_reshaper::{config::FormsFallback, form::Forms};
+use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshapeError, ReshaperConfig};
+
+#[cfg(feature = "alloc")]
+const TEXTS: [&str; 13] = [
+    "السلام عليكم",
+    "السَلَاْمٌ عَلَيْكُمْ",
+    "َسلام",
+    "من به reshape نیاز دارم",
+    "",
+    "الله أكبر",
+    "بسم الله الرحمن الرحيم",
+    "صلى الله عليه وسلم",
+    "لا\u{200D}ب\u{200D} \u{200D}ـــب",
+    "ببببببببببببببببببببببببببببببببب",
+    "ڈھول ٠١٢. ہے. ۔",
+    "ﷲ hello لله",
+    "ځ ۍ ڼ ټ",
+];
+
+#[cfg(feature = "alloc")]
+fn check(config: ReshaperConfig) {
+    let reshaper = ArabicReshaper::new(config);
+
+    for text in TEXTS {
+        let expected = reshaper.reshape(text);
+
+        let mut chars = ['\0'; 64];
+        let len = reshaper.reshape_to_chars(text, &mut chars).unwrap();
+        assert_eq!(chars[..len].iter().collect::<String>(), expected, "{text}");
+
+        let mut bytes = [0; 256];
+        let len = reshaper.reshape_to_bytes(text, &mut bytes).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&bytes[..len]),
+            Ok(expected.as_str()),
+            "{text}"
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn same_as_reshape() {
+    check(ReshaperConfig::default());
+    check(ReshaperConfig::new(Language::Arabic, LigaturesFlags::all()));
+    check(ReshaperConfig::new(
+        Language::Urdu,
+        LigaturesFlags::default(),
+    ));
+    check(ReshaperConfig::new(Language::Pashto, LigaturesFlags::all()));
+
+    for (delete_harakat, shift_harakat_position, delete_tatweel, support_zwj) in [
+        (false, false, false, true),
+        (false, true, false, true),
+        (true, false, true, false),
+        (false, true, true, false),
+    ] {
+        check(ReshaperConfig {
+            delete_harakat,
+            shift_harakat_position,
+            delete_tatweel,
+            support_zwj,
+            ..ReshaperConfig::new(Language::Arabic, LigaturesFlags::all())
+        });
+    }
+
+    for forms_fallback in [FormsFallback::Zwj, FormsFallback::PrivateUse('\u{E000}')] {
+        check(ReshaperConfig {
+            forms_fallback,
+            ..ReshaperConfig::new(Language::Pashto, LigaturesFlags::default())
+        });
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn custom_ligatures() {
+    let mut config = ReshaperConfig::default();
+    config.add_ligature("ا ب", Forms::new('\u{E100}', '\0', '\0', '\u{E101}'));
+    config.add_ligature("الله عليه", Forms::new('\u{E102}', '\0', '\0', '\0'));
+
+    check(config);
+}
+
+#[test]
+fn buffer_too_small() {
+    let reshaper = ArabicReshaper::default();
+
+    let mut chars = ['\0'; 2];
+    assert_eq!(
+        reshaper.reshape_to_chars("سلام", &mut chars),
+        Err(ReshapeError::BufferTooSmall { needed: 3 })
+    );
+
+    let mut bytes = [0; 8];
+    assert_eq!(
+        reshaper.reshape_to_bytes("سلام", &mut bytes),
+        Err(ReshapeError::BufferTooSmall { needed: 9 })
+    );
+    assert_eq!(reshaper.reshape_to_bytes("سلام", &mut [0; 9]), Ok(9));
+}
+
+#[test]
+fn segment_too_long() {
+    let reshaper = ArabicReshaper::default();
+    let text = "ب".repeat(200);
+
+    let mut chars = ['\0'; 256];
+    assert_eq!(
+        reshaper.reshape_to_chars(&text, &mut chars),
+        Err(ReshapeError::SegmentTooLong { position: 256 })
+    );
+
+    // words are reshaped separately, so only a single word has to fit
+    let text = "سلام ".repeat(100);
+    let mut chars = ['\0'; 400];
+    let len = reshaper.reshape_to_chars(&text, &mut chars).unwrap();
+    assert_eq!(len, 400);
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        chars[..len].iter().collect::<String>(),
+        reshaper.reshape(&text)
+    );
+}
+
+#[test]
+fn const_reshaping() {
+    static RESHAPER: ArabicReshaper = ArabicReshaper::new(ReshaperConfig::new(
+        Language::Arabic,
+        LigaturesFlags::default(),
+    ));
+    const RESHAPED: ([u8; 32], usize) = {
+        let mut buffer = [0; 32];
+        match RESHAPER.reshape_to_bytes("سلام دنیا", &mut buffer) {
+            Ok(len) => (buffer, len),
+            Err(_) => panic!("the buffer is too small"),
+        }
+    };
+
+    assert_eq!(
+        std::str::from_utf8(&RESHAPED.0[..RESHAPED.1]),
+        Ok("ﺳﻠﺎﻡ ﺩﻧﯿﺎ")
+    );
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "ttf-parser")]
+
+use ar_reshaper::{config::LigaturesFlags, font::ShapedFont, Language};
+
+/// The characters of the test font and their advances, the glyph 0 is `.notdef`
+const GLYPHS: [(char, u16); 9] = [
+    (' ', 250),
+    ('A', 600),
+    ('V', 600),
+    ('\u{064E}', 0),
+    ('\u{0645}', 400),
+    ('\u{FEB3}', 700),
+    ('\u{FEE1}', 450),
+    ('\u{FEFB}', 550),
+    ('\u{FEFC}', 500),
+];
+const NOTDEF_ADVANCE: u16 = 500;
+
+/// Glyph of the character in the test font
+fn gid(c: char) -> u16 {
+    GLYPHS.iter().position(|(g, _)| *g == c).unwrap() as u16 + 1
+}
+
+fn u16s(values: &[u16]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_be_bytes()).collect()
+}
+
+/// Build a minimal font with the [GLYPHS], the `kern` table has `AV` and `ﺳﻼ` (from left
+/// to right `ﻼﺳ`) pairs, and the `GPOS` table has a `kern` feature with `AV` pair.
+fn build_font(kern: bool, gpos: bool) -> Vec<u8> {
+    let num_glyphs = GLYPHS.len() as u16 + 1;
+    let mut tables: Vec<(&[u8; 4], Vec<u8>)> = Vec::new();
+
+    let mut head = Vec::new();
+    head.extend(0x0001_0000u32.to_be_bytes()); // version
+    head.extend(0u32.to_be_bytes()); // font revision
+    head.extend(0u32.to_be_bytes()); // checksum adjustment
+    head.extend(0x5F0F_3CF5u32.to_be_bytes()); // magic number
+    head.extend(u16s(&[0, 1000])); // flags, units per em
+    head.extend([0; 16]); // created, modified
+    head.extend(u16s(&[0, 0, 1000, 1000, 0, 8, 2, 0, 0]));
+    tables.push((b"head", head));
+
+    let mut hhea = Vec::new();
+    hhea.extend(0x0001_0000u32.to_be_bytes());
+    hhea.extend(u16s(&[800, (-200i16) as u16, 0, 700, 0, 0, 700, 1, 0, 0]));
+    hhea.extend(u16s(&[0, 0, 0, 0, 0, num_glyphs]));
+    tables.push((b"hhea", hhea));
+
+    let mut maxp = Vec::new();
+    maxp.extend(0x0000_5000u32.to_be_bytes());
+    maxp.extend(u16s(&[num_glyphs]));
+    tables.push((b"maxp", maxp));
+
+    let mut hmtx = u16s(&[NOTDEF_ADVANCE, 0]);
+    for (_, advance) in GLYPHS {
+        hmtx.extend(u16s(&[advance, 0]));
+    }
+    tables.push((b"hmtx", hmtx));
+
+    let mut groups: Vec<_> = GLYPHS.iter().map(|(c, _)| *c as u32).collect();
+    groups.sort_unstable();
+    let mut cmap = u16s(&[0, 1, 3, 10]);
+    cmap.extend(12u32.to_be_bytes());
+    cmap.extend(u16s(&[12, 0]));
+    cmap.extend((16 + 12 * groups.len() as u32).to_be_bytes());
+    cmap.extend(0u32.to_be_bytes());
+    cmap.extend((groups.len() as u32).to_be_bytes());
+    for c in groups {
+        let glyph = gid(char::from_u32(c).unwrap()) as u32;
+        [c, c, glyph]
+            .iter()
+            .for_each(|v| cmap.extend(v.to_be_bytes()));
+    }
+    tables.push((b"cmap", cmap));
+
+    if kern {
+        let mut pairs = [
+            (gid('A'), gid('V'), -80i16),
+            (gid('\u{FEFC}'), gid('\u{FEB3}'), -40),
+        ];
+        pairs.sort_unstable();
+        let mut table = u16s(&[0, 1, 0, 14 + 6 * pairs.len() as u16, 0x0001]);
+        table.extend(u16s(&[pairs.len() as u16, 12, 1, 0]));
+        for (left, right, value) in pairs {
+            table.extend(u16s(&[left, right, value as u16]));
+        }
+        tables.push((b"kern", table));
+    }
+
+    if gpos {
+        // header, empty script list, feature list, lookup list, pair adjustment
+        let mut table = u16s(&[1, 0, 10, 12, 26, 0]);
+        table.extend(u16s(&[1]));
+        table.extend(b"kern");
+        table.extend(u16s(&[8, 0, 1, 0]));
+        table.extend(u16s(&[1, 4, 2, 0, 1, 8]));
+        table.extend(u16s(&[1, 12, 0x0004, 0, 1, 18]));
+        table.extend(u16s(&[1, 1, gid('A')]));
+        table.extend(u16s(&[1, gid('V'), (-70i16) as u16]));
+        tables.push((b"GPOS", table));
+    }
+
+    tables.sort_by_key(|(tag, _)| **tag);
+
+    let mut font = Vec::new();
+    font.extend(0x0001_0000u32.to_be_bytes());
+    font.extend(u16s(&[tables.len() as u16, 0, 0, 0]));
+    let mut offset = 12 + 16 * tables.len() as u32;
+    for (tag, data) in &tables {
+        font.extend(*tag);
+        font.extend(0u32.to_be_bytes());
+        font.extend(offset.to_be_bytes());
+        font.extend((data.len() as u32).to_be_bytes());
+        offset += (data.len() as u32).next_multiple_of(4);
+    }
+    for (_, mut data) in tables {
+        data.resize(data.len().next_multiple_of(4), 0);
+        font.extend(data);
+    }
+    font
+}
+
+fn shaped_font(bytes: &[u8]) -> ShapedFont<'_> {
+    ShapedFont::new(bytes, Language::Arabic, LigaturesFlags::default()).unwrap()
+}
+
+#[test]
+fn measure_advances() {
+    let bytes = build_font(false, false);
+    let font = shaped_font(&bytes);
+
+    assert_eq!(font.measure(""), 0.0);
+    assert_eq!(font.measure("A V"), 1450.0);
+    assert_eq!(font.measure("AV"), 1200.0);
+    assert_eq!(font.measure("ﺳﻼﻡ"), 1650.0);
+    // harakat and the invisible characters have no width
+    assert_eq!(font.measure("ﺳَ\u{200D}ﻼﻡ"), 1650.0);
+}
+
+#[test]
+fn kern_table() {
+    let bytes = build_font(true, false);
+    let font = shaped_font(&bytes);
+
+    assert_eq!(font.measure("AV"), 1120.0);
+    assert_eq!(font.measure("VA"), 1200.0);
+    // the pairs are from left to right, so they are swapped in arabic text
+    assert_eq!(font.measure("ﺳﻼﻡ"), 1610.0);
+    assert_eq!(font.measure("ﻼﺳ"), 1200.0);
+    // harakat don't break the pairs
+    assert_eq!(font.measure("ﺳَﻼﻡ"), 1610.0);
+}
+
+#[test]
+fn gpos_kerning() {
+    let bytes = build_font(true, true);
+    let font = shaped_font(&bytes);
+
+    // the `GPOS` table is used instead of the `kern` table
+    assert_eq!(font.measure("AV"), 1130.0);
+    assert_eq!(font.measure("VA"), 1200.0);
+    assert_eq!(font.measure("ﺳﻼﻡ"), 1650.0);
+}
+
+#[cfg(feature = "layout")]
+#[test]
+fn wrap_with_kerning() {
+    use ar_reshaper::{layout::Layout, ReshaperConfig};
+
+    let bytes = build_font(true, false);
+    let font = shaped_font(&bytes);
+    let layout = Layout::new(ReshaperConfig::default());
+
+    // `AV AV` only fits with the kerning of the pairs
+    let lines = layout.wrap_with_font("AV AV", 2490.0, &font);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].width, 2490.0);
+}
+
+#[test]
+fn missing_glyphs() {
+    let bytes = build_font(false, false);
+    let font = shaped_font(&bytes);
+
+    assert!(font.missing_glyphs("ﺳﻼﻡ A").is_empty());
+    assert_eq!(font.missing_glyphs("Aب\u{200C}ﺳx"), [(1, 'ب'), (9, 'x')]);
+    // the missing characters are measured as `.notdef`
+    assert_eq!(font.measure("xA"), 1100.0);
+}
+
+#[test]
+fn reshaper_of_font() {
+    let bytes = build_font(false, false);
+    let font = shaped_font(&bytes);
+
+    // the isolated forms are not in the font, so the letters are used instead
+    assert_eq!(font.reshaper().reshape("م"), "م");
+    assert!(font.missing_glyphs("م").is_empty());
+
+    let face = ttf_parser::Face::parse(&bytes, 0).unwrap();
+    let from_face = ShapedFont::from_face(face, Language::Arabic, LigaturesFlags::default());
+    assert_eq!(from_face.face().units_per_em(), 1000);
+    assert_eq!(from_face.measure("A V"), font.measure("A V"));
+
+    assert!(ShapedFont::new(b"not a font", Language::Arabic, LigaturesFlags::default()).is_err());
+}
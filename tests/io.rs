@@ -0,0 +1,162 @@
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, ErrorKind, Read, Write};
+
+use ar_reshaper::{
+    io::{ReshapingReader, ReshapingWriter},
+    ArabicReshaper, ReshaperConfig,
+};
+
+const TEXT: &str = "السلام عليكم\r\nمن به reshape نیاز دارم\n\nالله أكبر";
+
+fn reshaped() -> String {
+    let reshaper = ArabicReshaper::default();
+    TEXT.split_inclusive('\n')
+        .map(|line| {
+            let content = line.trim_end_matches(['\r', '\n']);
+            reshaper.reshape(content) + &line[content.len()..]
+        })
+        .collect()
+}
+
+#[test]
+fn writer() {
+    let mut writer = ReshapingWriter::new(Vec::new());
+
+    // write the text in small pieces, even inside the characters
+    for chunk in TEXT.as_bytes().chunks(3) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    let output = writer.finish().unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), reshaped());
+}
+
+#[test]
+fn writer_lines() {
+    let mut output = Vec::new();
+    {
+        let mut writer = ReshapingWriter::with_config(ReshaperConfig::default(), &mut output);
+        writer.write_all("سلام\nدنیا".as_bytes()).unwrap();
+        writer.flush().unwrap();
+        // only the finished lines are written
+        assert_eq!(writer.get_ref().as_slice(), "ﺳﻼﻡ\n".as_bytes());
+    }
+
+    // the last line is written when the writer is dropped
+    assert_eq!(String::from_utf8(output).unwrap(), "ﺳﻼﻡ\nﺩﻧﯿﺎ");
+}
+
+#[test]
+fn writer_invalid_utf8() {
+    let mut writer = ReshapingWriter::new(Vec::new());
+
+    let error = writer.write_all(b"\xFF\xFE\n").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // the invalid line is dropped and writing can continue
+    writer.write_all("سلام\n".as_bytes()).unwrap();
+    assert_eq!(writer.finish().unwrap(), "ﺳﻼﻡ\n".as_bytes());
+}
+
+/// A writer that fails when more than `limit` bytes are written to it
+struct LimitedWriter {
+    written: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.written.len() >= self.limit {
+            return Err(ErrorKind::WriteZero.into());
+        }
+        let len = buf.len().min(self.limit - self.written.len());
+        self.written.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writer_failing() {
+    let inner = LimitedWriter {
+        written: Vec::new(),
+        limit: "ﺳﻼﻡ\n".len(),
+    };
+    let mut writer = ReshapingWriter::new(inner);
+    let text = "سلام\nدنیا\n".as_bytes();
+
+    // only the line that is written is consumed
+    assert_eq!(writer.write(text).unwrap(), "سلام\n".len());
+    let error = writer.write(&text["سلام\n".len()..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::WriteZero);
+    assert_eq!(writer.get_ref().written, "ﺳﻼﻡ\n".as_bytes());
+
+    // a line that is not valid is not consumed either
+    let mut writer = ReshapingWriter::new(Vec::new());
+    assert_eq!(writer.write(b"\n\xFF\n").unwrap(), 1);
+    assert_eq!(
+        writer.write(b"\xFF\n").unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(writer.finish().unwrap(), b"\n");
+}
+
+#[test]
+fn writer_retry() {
+    let inner = LimitedWriter {
+        written: Vec::new(),
+        limit: 0,
+    };
+    let mut writer = ReshapingWriter::new(inner);
+    write!(writer, "سل").unwrap();
+    let text = "ام\nدنیا\n".as_bytes();
+    assert_eq!(writer.write(text).unwrap_err().kind(), ErrorKind::WriteZero);
+
+    // the same text can be written again when the writer works
+    writer.get_mut().limit = usize::MAX;
+    writer.write_all(text).unwrap();
+    let output = writer.finish().unwrap().written;
+    assert_eq!(String::from_utf8(output).unwrap(), "ﺳﻼﻡ\nﺩﻧﯿﺎ\n");
+
+    // the rest of a line that is partly written is written first when the writer works
+    let inner = LimitedWriter {
+        written: Vec::new(),
+        limit: "ﺳﻼﻡ\nﺩﻧ".len(),
+    };
+    let mut writer = ReshapingWriter::new(inner);
+    writer.write_all("سلام\nدنیا\n".as_bytes()).unwrap();
+    assert_eq!(writer.flush().unwrap_err().kind(), ErrorKind::WriteZero);
+    writer.get_mut().limit = usize::MAX;
+    writer.flush().unwrap();
+    let output = writer.finish().unwrap().written;
+    assert_eq!(String::from_utf8(output).unwrap(), "ﺳﻼﻡ\nﺩﻧﯿﺎ\n");
+}
+
+#[test]
+fn reader() {
+    let mut reader = ReshapingReader::new(TEXT.as_bytes());
+    let mut output = String::new();
+    reader.read_to_string(&mut output).unwrap();
+    assert_eq!(output, reshaped());
+
+    let reader = ReshapingReader::new(TEXT.as_bytes());
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>().unwrap();
+    let expected: Vec<String> = reshaped().lines().map(String::from).collect();
+    assert_eq!(lines, expected);
+
+    let mut reader = ReshapingReader::new(TEXT.as_bytes());
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert_eq!(line, "ﺍﻟﺴﻼﻡ ﻋﻠﻴﻜﻢ\r\n");
+}
+
+#[test]
+fn reader_invalid_utf8() {
+    let mut reader = ReshapingReader::new(&b"\xFF\xFE\n"[..]);
+    let error = reader.read_to_string(&mut String::new()).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{
+    justify::{Justifier, KashidaRule},
+    ArabicReshaper, ReshaperConfig,
+};
+
+fn justifier() -> Justifier {
+    Justifier::new(ReshaperConfig::default())
+}
+
+#[test]
+fn fills_the_width() {
+    let justified = justifier().justify("سلام دنیا", 14);
+
+    assert_eq!(justified, "ﺳـــﻼﻡ ﺩﻧﯿـــﺎ");
+    assert_eq!(justified.chars().count(), 14);
+}
+
+#[test]
+fn not_justified() {
+    let reshaper = ArabicReshaper::default();
+
+    // already long enough
+    assert_eq!(
+        justifier().justify("سلام دنیا", 5),
+        reshaper.reshape("سلام دنیا")
+    );
+    // there are no joined letters
+    assert_eq!(
+        justifier().justify("hello ودا", 20),
+        reshaper.reshape("hello ودا")
+    );
+}
+
+#[test]
+fn lam_alef() {
+    assert_eq!(justifier().justify("لا", 5), "ﻻ");
+
+    // the letters of lam-alef are never separated, even without the ligature
+    let config = ReshaperConfig {
+        support_ligatures: false,
+        ..Default::default()
+    };
+    assert_eq!(Justifier::new(config).justify("الان", 8), "ﺍﻟﺎﻥ");
+}
+
+#[test]
+fn rules() {
+    // the tatweel that is already in the text is extended first
+    assert_eq!(justifier().justify("بـبب سبب", 12), "ﺑـــﺒﺐ ﺳــﺒﺐ");
+    assert_eq!(justifier().justify("مدرسة كتاب", 12), "ﻣﺪﺭﺳـﺔ ﻛﺘـﺎﺏ");
+
+    let justifier = justifier().with_rules(&[KashidaRule::BeforeFinalWaw]);
+    assert_eq!(justifier.justify("سلام دنیا", 10), "ﺳﻼﻡ ﺩﻧﯿﺎ");
+    assert_eq!(
+        justifier.justify("سلام علیکم و شوق", 18),
+        "ﺳﻼﻡ ﻋﻠﯿﮑﻢ ﻭ ﺷـــﻮﻕ"
+    );
+}
+
+#[test]
+fn harakat() {
+    let config = ReshaperConfig {
+        delete_harakat: false,
+        ..Default::default()
+    };
+
+    // the harakat are not counted and stay on their letter
+    assert_eq!(Justifier::new(config).justify("سَلام", 6), "ﺳَـــﻼﻡ");
+}
+
+#[test]
+fn width_callback() {
+    let width = |c| match c {
+        '\u{0640}' => 0.5,
+        _ => 1.0,
+    };
+
+    assert_eq!(
+        justifier().justify_with("سلام دنیا", 10.0, width),
+        "ﺳــﻼﻡ ﺩﻧﯿــﺎ"
+    );
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "layout")]
+
+use ar_reshaper::{layout::Layout, ReshaperConfig};
+
+fn wrap(text: &str, max_width: f32) -> Vec<String> {
+    let layout = Layout::new(ReshaperConfig::default());
+    let lines = layout.wrap(text, max_width, |_| 1.0);
+    lines.into_iter().map(|line| line.text).collect()
+}
+
+#[test]
+fn word_wrap() {
+    assert_eq!(
+        wrap("من به reshape نیاز دارم", 9.0),
+        ["ﻣﻦ ﺑﻪ", "reshape", "ﻧﯿﺎﺯ ﺩﺍﺭﻡ"]
+    );
+    assert_eq!(wrap("سلام دنیا", 20.0), ["ﺳﻼﻡ ﺩﻧﯿﺎ"]);
+    assert!(wrap("", 5.0).is_empty());
+}
+
+#[test]
+fn measured_after_reshaping() {
+    // the ligature of `الله` is a single character, so the first line fits 5
+    assert_eq!(
+        wrap("بسم الله الرحمن الرحيم", 10.0),
+        ["ﺑﺴﻢ ﷲ", "ﺍﻟﺮﺣﻤﻦ", "ﺍﻟﺮﺣﻴﻢ"]
+    );
+
+    // lam-alef is narrower than its letters
+    let layout = Layout::new(ReshaperConfig::default());
+    let lines = layout.wrap("سلام سلام", 7.0, |_| 1.0);
+    assert_eq!(lines[0].text, "ﺳﻼﻡ ﺳﻼﻡ");
+    assert_eq!(lines[0].width, 7.0);
+}
+
+#[test]
+fn measured_as_whole_lines() {
+    // the words of a sentence ligature are measured together, they are wider one by one
+    let layout = Layout::new(ReshaperConfig::new(
+        ar_reshaper::Language::Arabic,
+        ar_reshaper::config::LigaturesFlags::all(),
+    ));
+    let lines = layout.wrap("صلى الله عليه وسلم سلام", 10.0, |_| 1.0);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].text, "\u{FDFA} ﺳﻼﻡ");
+    assert_eq!(lines[0].width, 5.0);
+}
+
+#[test]
+fn line_breaks() {
+    let layout = Layout::new(ReshaperConfig::default());
+    let lines = layout.wrap("سلام\r\nدنیا\n", 50.0, |_| 1.0);
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        (lines[0].text.as_str(), lines[0].range.clone()),
+        ("ﺳﻼﻡ", 0..10)
+    );
+    assert_eq!(
+        (lines[1].text.as_str(), lines[1].range.clone()),
+        ("ﺩﻧﯿﺎ", 10..19)
+    );
+}
+
+#[test]
+fn long_words() {
+    // a word that doesn't fit get its own line
+    assert_eq!(wrap("بببببببببب سلام", 5.0), ["ﺑﺒﺒﺒﺒﺒﺒﺒﺒﺐ", "ﺳﻼﻡ"]);
+}
+
+#[test]
+fn edge_forms() {
+    // the letters at the end and start of the lines are not joined
+    assert_eq!(wrap("ببب ببب", 3.0), ["ﺑﺒﺐ", "ﺑﺒﺐ"]);
+    assert_eq!(wrap("ببب\u{200B}ببب", 4.0), ["ﺑﺒﺐ\u{200B}", "ﺑﺒﺐ"]);
+}
+
+#[test]
+fn line_break_after_fitting_line() {
+    assert_eq!(wrap("ab cd\nefghijk lm", 5.0), ["ab cd", "efghijk", "lm"]);
+    assert_eq!(wrap("ab cd ef\ngh", 5.0), ["ab cd", "ef", "gh"]);
+}
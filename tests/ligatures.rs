@@ -0,0 +1,139 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
+
+#[test]
+fn all_ligatures_reshaping() {
+    let reshaper =
+        ArabicReshaper::new(ReshaperConfig::new(Language::Arabic, LigaturesFlags::all()));
+
+    let cases = [
+        // sentences take precedence over the words and letters inside them
+        ("صلى الله عليه وسلم ص", "ﷺ ﺹ"),
+        ("بسم الله الرحمن الرحيم", "﷽"),
+        ("عليه", "ﷷ"),
+        // overlapping letter ligatures are replaced one after another
+        ("اعتماد", "ﺍﻋﳣﺩ"),
+        ("باليوم العالمي للغة العربية", "ﺑﺎﻟﻴﻮﻡ ﺍﻟﻌﺎﶭ ﻟﻠﻐﺔ ﺍﻟﻌﺮﺑﻴﺔ"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+    }
+}
+
+#[test]
+fn overlapping_ligatures() {
+    let reshaper =
+        ArabicReshaper::new(ReshaperConfig::new(Language::Arabic, LigaturesFlags::all()));
+
+    // the output of the reshaper before the ligatures were matched in a single pass
+    let cases = [
+        ("نجح", "ﶽ"),
+        ("ليمم", "ﻟﳰ"),
+        ("محي", "ﳏ"),
+        ("فحي", "ﲿﴜ"),
+        ("جذبما", "ﺟﺬﲟﲈ"),
+        ("سمم", "ﲰ"),
+        ("تخي", "ﲣ"),
+        ("يحى", "ﳛ"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+
+        let mut buffer = ['\0'; 16];
+        let len = reshaper.reshape_to_chars(before, &mut buffer).unwrap();
+        assert_eq!(String::from_iter(&buffer[..len]), after);
+    }
+}
+
+#[test]
+fn ligatures_with_harakat() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        delete_harakat: false,
+        ..Default::default()
+    });
+
+    // harakat are not part of the ligature but stay in the output
+    assert_eq!(reshaper.reshape("لَا"), "ﻻَ");
+}
+
+#[test]
+fn ligature_patterns() {
+    use ar_reshaper::prelude::LigatureNames;
+
+    let mut config = ReshaperConfig::default();
+    config.update_ligature(LigatureNames::RIAL_SIGN, true);
+    config.update_ligature(LigatureNames::ARABIC_LIGATURE_AKBAR, true);
+    let reshaper = ArabicReshaper::new(config);
+
+    let cases = [
+        // both persian and arabic yeh
+        ("۱۰۰ ریال", "۱۰۰ \u{FDFC}"),
+        ("۱۰۰ ريال", "۱۰۰ \u{FDFC}"),
+        // both arabic kaf and keheh
+        ("الله أكبر", "\u{FDF2} \u{FDF3}"),
+        ("الله أکبر", "\u{FDF2} \u{FDF3}"),
+        // whole words only
+        ("بالله", "ﺑﺎﻟﻠﻪ"),
+        ("اللهم", "ﺍﻟﻠﻬﻢ"),
+        ("(الله)", "(\u{FDF2})"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+    }
+
+    assert_eq!(reshaper.unshape("۱۰۰ \u{FDFC}"), "۱۰۰ ریال");
+}
+
+#[test]
+fn custom_ligatures() {
+    use ar_reshaper::form::Forms;
+
+    let mut config = ReshaperConfig::default();
+    config.add_ligature(
+        "راست",
+        Forms::new('\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}'),
+    );
+    // only have the isolated form
+    config.add_ligature("سلام", Forms::new('\u{E010}', '\0', '\0', '\0'));
+    let reshaper = ArabicReshaper::new(config);
+
+    let cases = [
+        ("راست", "\u{E000}"),
+        ("راستی", "\u{E001}ﯽ"),
+        ("زبان راست", "ﺯﺑﺎﻥ \u{E000}"),
+        ("سلام دنیا", "\u{E010} ﺩﻧﯿﺎ"),
+        // the ligature doesn't have the initial form
+        ("سلامی", "ﺳﻼﻣﯽ"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+    }
+
+    assert_eq!(reshaper.unshape("\u{E001}ﯽ"), "راستی");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn custom_ligatures_serde() {
+    use ar_reshaper::form::Forms;
+
+    let mut config = ReshaperConfig::default();
+    config.add_ligature(
+        "راست",
+        Forms::new('\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}'),
+    );
+
+    let json = serde_json::to_string(&config).unwrap();
+    let config: ReshaperConfig = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        config.custom_ligatures[0].forms,
+        Forms::new('\u{E000}', '\u{E001}', '\u{E002}', '\u{E003}')
+    );
+    assert_eq!(ArabicReshaper::new(config).reshape("راست"), "\u{E000}");
+}
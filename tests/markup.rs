@@ -0,0 +1,121 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{
+    markup::{BBCode, Html, Placeholders, Token, Tokenizer, UnityRichText},
+    ArabicReshaper,
+};
+
+const RESHAPER: ArabicReshaper = ArabicReshaper::new(ar_reshaper::ReshaperConfig::new(
+    ar_reshaper::Language::Arabic,
+    ar_reshaper::config::LigaturesFlags::default(),
+));
+
+#[test]
+fn html() {
+    assert_eq!(
+        RESHAPER.reshape_markup("ب<b class=\"x\">ب</b>ب<!-- ب -->ب", Html),
+        "ﺑ<b class=\"x\">ﺒ</b>ﺒ<!-- ب -->ﺐ"
+    );
+    // line breaks and entities don't join the letters
+    assert_eq!(
+        RESHAPER.reshape_markup("ب<br/>ب&amp;ب", Html),
+        "ﺏ<br/>ﺏ&amp;ﺏ"
+    );
+    // not a tag
+    assert_eq!(RESHAPER.reshape_markup("ب < ب", Html), "ﺏ < ﺏ");
+}
+
+#[test]
+fn ligatures_over_tags() {
+    let reshaper = ArabicReshaper::default();
+
+    // the letters are joined over the tags, but each of them stays inside its tags
+    assert_eq!(reshaper.reshape_markup("سلام", Html), "ﺳﻼﻡ");
+    assert_eq!(reshaper.reshape_markup("سل<b>ا</b>م", Html), "ﺳﻠ<b>ﺎ</b>ﻡ");
+    assert_eq!(reshaper.reshape_markup("<b>سل</b>ام", Html), "<b>ﺳﻠ</b>ﺎﻡ");
+    assert_eq!(reshaper.reshape_markup("<b>سلا</b>م", Html), "<b>ﺳﻼ</b>ﻡ");
+}
+
+#[test]
+fn unity_rich_text() {
+    assert_eq!(
+        RESHAPER.reshape_markup(
+            "<color=#ff0000>ب</color>ب<sprite=1>ب<size=20>بب</size>",
+            UnityRichText
+        ),
+        "<color=#ff0000>ﺑ</color>ﺐ<sprite=1>ﺑ<size=20>ﺒﺐ</size>"
+    );
+}
+
+#[test]
+fn bbcode() {
+    assert_eq!(
+        RESHAPER.reshape_markup("[url=https://example.com]ب[/url]ب [ب] [not closed", BBCode),
+        "[url=https://example.com]ﺑ[/url]ﺐ [ﺏ] [not closed"
+    );
+}
+
+#[test]
+fn placeholders() {
+    assert_eq!(
+        RESHAPER.reshape_markup("ب%sب {0}ب %1$dب {{ب", Placeholders),
+        "ﺏ%sﺏ {0}ﺏ %1$dﺏ {{ﺏ"
+    );
+}
+
+#[test]
+fn combined() {
+    assert_eq!(
+        RESHAPER.reshape_markup("<i>سلام</i> {name}", (Html, Placeholders)),
+        "<i>ﺳﻠﺎﻡ</i> {name}"
+    );
+}
+
+/// Mentions like `@name` are placeholders
+struct Mentions;
+
+impl Tokenizer for Mentions {
+    fn token<'a>(&mut self, text: &'a str) -> Option<(Token<'a>, usize)> {
+        let name = text.strip_prefix('@')?;
+        let len = 1 + name
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(name.len());
+        (len > 1).then(|| (Token::Placeholder(&text[..len]), len))
+    }
+}
+
+#[test]
+fn custom_tokenizer() {
+    assert_eq!(
+        RESHAPER.reshape_markup("ب@aliب [b]ب[/b]", (Mentions, BBCode)),
+        "ﺏ@aliﺏ [b]ﺏ[/b]"
+    );
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn visual_ordering() {
+    let reshaper = ArabicReshaper::new(ar_reshaper::ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    let cases = [
+        // the tags stay around the letters that are inside them
+        ("<b>سلام</b> دنیا", "ﺎﯿﻧﺩ <b>ﻡﻼﺳ</b>"),
+        ("<b>سلام <i>دنیا</i></b>", "<b><i>ﺎﯿﻧﺩ</i> ﻡﻼﺳ</b>"),
+        ("سلام <b>hello world</b> دنیا", "ﺎﯿﻧﺩ <b>hello world</b> ﻡﻼﺳ"),
+        ("[b]سلام[/b] {0} دنیا", "ﺎﯿﻧﺩ {0} [b]ﻡﻼﺳ[/b]"),
+        // the tags that aren't a pair stay between the same letters
+        ("سل<b>ا</b>م", "ﻡ<b>ﺎ</b>ﻠﺳ"),
+        ("سلام<!-- x --> دنیا", "ﺎﯿﻧﺩ <!-- x -->ﻡﻼﺳ"),
+        ("سلام <b>دنیا", "ﺎﯿﻧﺩ<b> ﻡﻼﺳ"),
+    ];
+
+    for (text, expected) in cases {
+        assert_eq!(
+            reshaper.reshape_markup(text, (Html, BBCode, Placeholders)),
+            expected
+        );
+    }
+}
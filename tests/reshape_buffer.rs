@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{ArabicReshaper, ReshapeBuffer};
+
+const TEXTS: [&str; 5] = [
+    "السلام عليكم",
+    "السَلَاْمٌ عَلَيْكُمْ",
+    "من به reshape نیاز دارم",
+    "",
+    "الله أكبر",
+];
+
+#[test]
+fn reshape_into() {
+    let reshaper = ArabicReshaper::default();
+
+    let mut out = String::from("> ");
+    reshaper.reshape_into("سلام", &mut out);
+    reshaper.reshape_into(" دنیا", &mut out);
+    assert_eq!(out, "> ﺳﻼﻡ ﺩﻧﯿﺎ");
+
+    let mut out = String::new();
+    reshaper.reshape_fmt("سلام", &mut out).unwrap();
+    assert_eq!(out, "ﺳﻼﻡ");
+}
+
+#[test]
+fn reshape_buffer() {
+    let mut reshaper = ArabicReshaper::default();
+    let mut buffer = ReshapeBuffer::new();
+
+    for (delete_harakat, shift_harakat_position) in [(true, false), (false, false), (false, true)] {
+        reshaper.modify_config(|c| {
+            c.delete_harakat = delete_harakat;
+            c.shift_harakat_position = shift_harakat_position;
+        });
+
+        for text in TEXTS {
+            let expected = reshaper.reshape(text);
+            assert_eq!(buffer.reshape(&reshaper, text), expected);
+
+            let mut out = String::from("> ");
+            buffer.reshape_into(&reshaper, text, &mut out);
+            assert_eq!(out, format!("> {expected}"));
+
+            out.clear();
+            buffer.reshape_fmt(&reshaper, text, &mut out).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{config::LigaturesFlags, prelude::*, ArabicReshaper, Language, ReshaperConfig};
+
+const TEXTS: [&str; 12] = [
+    "السلام عليكم",
+    "السَلَاْمٌ عَلَيْكُمْ",
+    "َسلام",
+    "من به reshape نیاز دارم",
+    "",
+    "الله أكبر",
+    "بسم الله الرحمن الرحيم",
+    "صلى الله عليه وسلم",
+    "لا\u{200D}ب\u{200D} \u{200D}ـــب",
+    "ببببببببببببببببببببببببببببببببب",
+    "ڈھول ٠١٢. ہے. ۔",
+    "ﷲ hello لله",
+];
+
+fn check(config: ReshaperConfig) {
+    let reshaper = ArabicReshaper::new(config.clone());
+
+    for text in TEXTS {
+        let reshaped: String = text
+            .chars()
+            .reshape_chars_with_config(config.clone())
+            .collect();
+        assert_eq!(reshaped, reshaper.reshape(text), "{text}");
+    }
+}
+
+#[test]
+fn same_as_reshape() {
+    check(ReshaperConfig::default());
+    check(ReshaperConfig::new(Language::Arabic, LigaturesFlags::all()));
+    check(ReshaperConfig::new(
+        Language::Urdu,
+        LigaturesFlags::default(),
+    ));
+    check(ReshaperConfig::new(Language::Pashto, LigaturesFlags::all()));
+
+    for (delete_harakat, shift_harakat_position, delete_tatweel, support_zwj) in [
+        (false, false, false, true),
+        (false, true, false, true),
+        (true, false, true, false),
+        (false, true, true, false),
+    ] {
+        check(ReshaperConfig {
+            delete_harakat,
+            shift_harakat_position,
+            delete_tatweel,
+            support_zwj,
+            ..ReshaperConfig::new(Language::Arabic, LigaturesFlags::all())
+        });
+    }
+}
+
+#[test]
+fn bounded_lookahead() {
+    // the underlying iterator never ends, so the characters can't be buffered until the end
+    let reshaped: String = "سلام "
+        .chars()
+        .cycle()
+        .reshape_chars_default()
+        .take(10)
+        .collect();
+    assert_eq!(reshaped, "ﺳﻼﻡ ﺳﻼﻡ ﺳﻼ");
+
+    let reshaped: String = "ب"
+        .chars()
+        .cycle()
+        .reshape_chars_default()
+        .take(3)
+        .collect();
+    assert_eq!(reshaped, "ﺑﺒﺒ");
+}
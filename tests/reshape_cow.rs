@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+
+use ar_reshaper::{config::LigaturesFlags, prelude::*, ArabicReshaper, Language, ReshaperConfig};
+
+const TEXTS: [&str; 10] = [
+    "السلام عليكم",
+    "من به reshape نیاز دارم",
+    "no reshape",
+    "",
+    "harakat َ ُ",
+    "tatweel ـ",
+    "zwj \u{200D}",
+    "digits ٠١٢",
+    "comma ، ؟",
+    "الله أكبر",
+];
+
+#[test]
+fn same_as_reshape() {
+    let mut reshaper = ArabicReshaper::default();
+
+    for (delete_harakat, shift_harakat_position, delete_tatweel) in [
+        (true, false, false),
+        (false, false, false),
+        (false, true, false),
+        (false, false, true),
+    ] {
+        reshaper.modify_config(|config| {
+            config.delete_harakat = delete_harakat;
+            config.shift_harakat_position = shift_harakat_position;
+            config.delete_tatweel = delete_tatweel;
+        });
+
+        for text in TEXTS {
+            assert_eq!(reshaper.reshape_cow(text), reshaper.reshape(text), "{text}");
+        }
+    }
+
+    let urdu = ArabicReshaper::new(ReshaperConfig::new(
+        Language::Urdu,
+        LigaturesFlags::default(),
+    ));
+    for text in TEXTS {
+        assert_eq!(urdu.reshape_cow(text), urdu.reshape(text), "{text}");
+    }
+}
+
+#[test]
+fn borrowed() {
+    let reshaper = ArabicReshaper::default();
+
+    for text in ["no reshape", "", "comma ، ؟"] {
+        assert!(
+            matches!(reshaper.reshape_cow(text), Cow::Borrowed(_)),
+            "{text}"
+        );
+    }
+
+    // harakat are deleted by default
+    for text in ["من به reshape نیاز دارم", "zwj \u{200D}", "harakat َ ُ"] {
+        assert!(
+            matches!(reshaper.reshape_cow(text), Cow::Owned(_)),
+            "{text}"
+        );
+    }
+
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        delete_tatweel: true,
+        ..Default::default()
+    });
+    assert!(matches!(reshaper.reshape_cow("tatweel ـ"), Cow::Owned(_)));
+
+    let mut reshaper = ArabicReshaper::new(ReshaperConfig {
+        delete_harakat: false,
+        ..Default::default()
+    });
+    assert!(matches!(
+        reshaper.reshape_cow("harakat َ ُ"),
+        Cow::Borrowed(_)
+    ));
+
+    reshaper.modify_config(|config| config.shift_harakat_position = true);
+    assert!(matches!(reshaper.reshape_cow("harakat َ ُ"), Cow::Owned(_)));
+}
+
+#[test]
+fn cow_iterator() {
+    let reshaped: Vec<_> = ["سلام", "hello"].into_iter().reshape_cow_default().collect();
+    assert!(matches!(&reshaped[0], Cow::Owned(s) if s == "ﺳﻼﻡ"));
+    assert!(matches!(&reshaped[1], Cow::Borrowed("hello")));
+
+    let lines = vec![String::from("سلام"), String::from("hello")];
+    let reshaped: Vec<_> = lines
+        .into_iter()
+        .reshape_cow_with_config(ReshaperConfig::default())
+        .collect();
+    assert_eq!(reshaped, ["ﺳﻼﻡ", "hello"]);
+}
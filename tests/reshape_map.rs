@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::ArabicReshaper;
+
+#[test]
+fn reshape_with_map() {
+    let reshaper = ArabicReshaper::default();
+    let cases = [
+        ("لا بد", "ﻻ ﺑﺪ", vec![0..4, 4..5, 5..7, 7..9]),
+        (
+            "abc سلام",
+            "abc ﺳﻼﻡ",
+            vec![0..1, 1..2, 2..3, 3..4, 4..6, 6..10, 10..12],
+        ),
+        // ligatures cover all of their letters
+        (
+            "الله أكبر",
+            "ﷲ ﺃﻛﺒﺮ",
+            vec![0..8, 8..9, 9..11, 11..13, 13..15, 15..17],
+        ),
+        // deleted harakat are not covered
+        ("سَلام", "ﺳﻼﻡ", vec![0..2, 4..8, 8..10]),
+        ("", "", vec![]),
+    ];
+
+    for (before, after, map) in cases {
+        assert_eq!(reshaper.reshape_with_map(before), (after.to_string(), map));
+        assert_eq!(reshaper.reshape(before), after);
+    }
+}
+
+#[test]
+fn reshape_with_map_harakat() {
+    let mut reshaper = ArabicReshaper::default();
+    reshaper.modify_config(|c| c.delete_harakat = false);
+
+    assert_eq!(
+        reshaper.reshape_with_map("سَلام"),
+        ("ﺳَﻼﻡ".to_string(), vec![0..2, 2..4, 4..8, 8..10])
+    );
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn reshape_with_map_visual_ordering() {
+    let reshaper = ArabicReshaper::new(ar_reshaper::ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        reshaper.reshape_with_map("سلام abc"),
+        (
+            "abc ﻡﻼﺳ".to_string(),
+            vec![9..10, 10..11, 11..12, 8..9, 6..8, 2..6, 0..2]
+        )
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use ar_reshaper::{ArabicReshaper, ReshaperConfig};
 
 #[test]
@@ -0,0 +1,172 @@
+#![cfg(feature = "subtitles")]
+
+use ar_reshaper::{
+    subtitles::{reshape_subtitles, SubtitleFormat},
+    ArabicReshaper,
+};
+
+const RESHAPER: ArabicReshaper = ArabicReshaper::new(ar_reshaper::ReshaperConfig::new(
+    ar_reshaper::Language::Arabic,
+    ar_reshaper::config::LigaturesFlags::default(),
+));
+
+#[test]
+fn srt() {
+    let srt = "\u{FEFF}1\r\n\
+               00:00:01,000 --> 00:00:02,500 X1:10 X2:20\r\n\
+               <i>سلام</i> دنیا\r\n\
+               {\\an8}<font color=\"#ff0000\">من</font>\r\n\
+               \r\n\
+               2\r\n\
+               00:00:03,000 --> 00:00:04,000\r\n\
+               سل<b>ا</b>م\r\n";
+
+    // the letters are still joined over the tag
+    let expected = "\u{FEFF}1\r\n\
+                    00:00:01,000 --> 00:00:02,500 X1:10 X2:20\r\n\
+                    <i>ﺳﻠﺎﻡ</i> ﺩﻧﯿﺎ\r\n\
+                    {\\an8}<font color=\"#ff0000\">ﻣﻦ</font>\r\n\
+                    \r\n\
+                    2\r\n\
+                    00:00:03,000 --> 00:00:04,000\r\n\
+                    ﺳﻠ<b>ﺎ</b>ﻡ\r\n";
+
+    assert_eq!(SubtitleFormat::detect(srt), Some(SubtitleFormat::Srt));
+    assert_eq!(
+        reshape_subtitles(&RESHAPER, srt, SubtitleFormat::Srt),
+        expected
+    );
+}
+
+#[test]
+fn srt_text_like_tags() {
+    // a `<` or `{` that doesn't start a tag is a part of the text
+    let dialogue = "سلام <3 دنیا > من {سلام} a < b";
+    let srt = format!("1\n00:00:01,000 --> 00:00:02,000\n{dialogue}\n");
+
+    assert_eq!(
+        reshape_subtitles(&RESHAPER, &srt, SubtitleFormat::Srt),
+        format!(
+            "1\n00:00:01,000 --> 00:00:02,000\n{}\n",
+            RESHAPER.reshape(dialogue)
+        )
+    );
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn srt_visual_ordering() {
+    let reshaper = ArabicReshaper::new(ar_reshaper::ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+    let srt = "1\n00:00:01,000 --> 00:00:02,000\n<i>سلام</i> دنیا\n";
+
+    assert_eq!(
+        reshape_subtitles(&reshaper, srt, SubtitleFormat::Srt),
+        "1\n00:00:01,000 --> 00:00:02,000\nﺎﯿﻧﺩ <i>ﻡﻼﺳ</i>\n"
+    );
+}
+
+#[test]
+fn webvtt() {
+    let vtt = "WEBVTT - سلام\n\
+               \n\
+               STYLE\n\
+               ::cue(.سلام) { color: yellow }\n\
+               \n\
+               NOTE سلام\n\
+               \n\
+               intro\n\
+               00:01.000 --> 00:02.000 align:start position:10%\n\
+               <v محمد>سلام</v> <c.yellow>دنیا</c>\n\
+               <00:01.500>من\n\
+               \n\
+               00:03.000 --> 00:04.000\n\
+               لا\n";
+
+    let expected = "WEBVTT - سلام\n\
+                    \n\
+                    STYLE\n\
+                    ::cue(.سلام) { color: yellow }\n\
+                    \n\
+                    NOTE سلام\n\
+                    \n\
+                    intro\n\
+                    00:01.000 --> 00:02.000 align:start position:10%\n\
+                    <v محمد>ﺳﻠﺎﻡ</v> <c.yellow>ﺩﻧﯿﺎ</c>\n\
+                    <00:01.500>ﻣﻦ\n\
+                    \n\
+                    00:03.000 --> 00:04.000\n\
+                    ﻟﺎ\n";
+
+    assert_eq!(SubtitleFormat::detect(vtt), Some(SubtitleFormat::WebVtt));
+    assert_eq!(
+        reshape_subtitles(&RESHAPER, vtt, SubtitleFormat::WebVtt),
+        expected
+    );
+}
+
+#[test]
+fn ass() {
+    let ass = "[Script Info]\n\
+               Title: سلام\n\
+               \n\
+               [V4+ Styles]\n\
+               Format: Name, Fontname, Fontsize\n\
+               Style: Default,Arial,20\n\
+               \n\
+               [Events]\n\
+               Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+               Dialogue: 0,0:00:01.00,0:00:02.00,Default,علی,0,0,0,,{\\i1}سلام{\\i0}، دنیا\n\
+               Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,سل{\\b1}ام\\Nمن\\hتو\n\
+               Comment: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,سلام\n";
+
+    let expected = "[Script Info]\n\
+                    Title: سلام\n\
+                    \n\
+                    [V4+ Styles]\n\
+                    Format: Name, Fontname, Fontsize\n\
+                    Style: Default,Arial,20\n\
+                    \n\
+                    [Events]\n\
+                    Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+                    Dialogue: 0,0:00:01.00,0:00:02.00,Default,علی,0,0,0,,{\\i1}ﺳﻠﺎﻡ{\\i0}، ﺩﻧﯿﺎ\n\
+                    Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,ﺳﻠ{\\b1}ﺎﻡ\\Nﻣﻦ\\hﺗﻮ\n\
+                    Comment: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,سلام\n";
+
+    assert_eq!(SubtitleFormat::detect(ass), Some(SubtitleFormat::Ass));
+    assert_eq!(
+        reshape_subtitles(&RESHAPER, ass, SubtitleFormat::Ass),
+        expected
+    );
+
+    // SSA has a different Format line
+    let ssa = "[Events]\n\
+               Format: Marked, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+               Dialogue: Marked=0,0:00:01.00,0:00:02.00,Default,,0,0,0,,سلام, دنیا\n";
+    assert_eq!(
+        reshape_subtitles(&RESHAPER, ssa, SubtitleFormat::Ass),
+        "[Events]\n\
+         Format: Marked, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n\
+         Dialogue: Marked=0,0:00:01.00,0:00:02.00,Default,,0,0,0,,ﺳﻠﺎﻡ, ﺩﻧﯿﺎ\n"
+    );
+}
+
+#[test]
+fn format() {
+    assert_eq!(
+        SubtitleFormat::from_extension("SRT"),
+        Some(SubtitleFormat::Srt)
+    );
+    assert_eq!(
+        SubtitleFormat::from_extension("vtt"),
+        Some(SubtitleFormat::WebVtt)
+    );
+    assert_eq!(
+        SubtitleFormat::from_extension("ssa"),
+        Some(SubtitleFormat::Ass)
+    );
+    assert_eq!(SubtitleFormat::from_extension("txt"), None);
+    assert_eq!(SubtitleFormat::detect("سلام"), None);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{form::Forms, ArabicReshaper, Language, ReshapeError, ReshaperConfig};
+
+/// BEH without the isolated and final forms and ALEF
+static MALFORMED_LETTERS: [(char, Forms); 2] = [
+    ('\u{0628}', Forms::new('\0', '\u{FE91}', '\u{FE92}', '\0')),
+    ('\u{0627}', Forms::new('\u{FE8D}', '\0', '\0', '\u{FE8E}')),
+];
+
+#[test]
+fn try_reshape() {
+    let reshaper = ArabicReshaper::default();
+
+    for text in ["سلام دنیا", "الله أكبر", "abc", ""] {
+        assert_eq!(reshaper.try_reshape(text), Ok(reshaper.reshape(text)));
+    }
+}
+
+#[test]
+fn harakat_at_start() {
+    let mut reshaper = ArabicReshaper::default();
+    reshaper.modify_config(|c| c.delete_harakat = false);
+
+    assert_eq!(
+        reshaper.try_reshape("\u{064E}بَ"),
+        Ok("\u{064E}ﺏَ".to_string())
+    );
+
+    reshaper.modify_config(|c| c.shift_harakat_position = true);
+    assert_eq!(reshaper.try_reshape("\u{064E}"), Ok("\u{064E}".to_string()));
+}
+
+#[test]
+fn malformed_language() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        language: Language::Custom(&MALFORMED_LETTERS),
+        ..Default::default()
+    });
+
+    assert_eq!(reshaper.try_reshape("با"), Ok("ﺑﺎ".to_string()));
+    assert_eq!(
+        reshaper.try_reshape("اب"),
+        Err(ReshapeError::MissingForm {
+            letter: 'ب',
+            position: 2
+        })
+    );
+
+    // letters that can't be reshaped are kept as they are
+    assert_eq!(reshaper.reshape("اب"), "ﺍب");
+}
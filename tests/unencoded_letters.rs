@@ -0,0 +1,105 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{
+    config::{FormsFallback, LigaturesFlags},
+    iterator::ArabicReshaperCharsExt,
+    ArabicReshaper, Language, ReshaperConfig,
+};
+
+fn config(language: Language, forms_fallback: FormsFallback) -> ReshaperConfig {
+    let mut config = ReshaperConfig::new(language, LigaturesFlags::default());
+    config.forms_fallback = forms_fallback;
+    config
+}
+
+fn reshaper(language: Language, forms_fallback: FormsFallback) -> ArabicReshaper {
+    ArabicReshaper::new(config(language, forms_fallback))
+}
+
+#[test]
+fn pashto_reshaping() {
+    let cases = [
+        (
+            FormsFallback::Base,
+            "ښه راغلاست",
+            "\u{069A}\u{FEEA} \u{FEAD}\u{FE8D}\u{FECF}\u{FEE0}\u{FE8E}\u{FEB3}\u{FE96}",
+        ),
+        (
+            FormsFallback::Base,
+            "څنګه یې؟",
+            "\u{0685}\u{FEE8}\u{06AB}\u{FEEA} \u{FBFE}\u{FBE5}\u{061F}",
+        ),
+        (
+            FormsFallback::Zwj,
+            "څنګه یې؟",
+            "\u{0685}\u{200D}\u{FEE8}\u{200D}\u{06AB}\u{200D}\u{FEEA} \u{FBFE}\u{FBE5}\u{061F}",
+        ),
+        (
+            FormsFallback::Zwj,
+            "مڼه ډېره ښه ده",
+            "\u{FEE3}\u{200D}\u{06BC}\u{200D}\u{FEEA} \u{0689}\u{FBE6}\u{FEAE}\u{FEE9} \u{069A}\u{200D}\u{FEEA} \u{FEA9}\u{FEE9}",
+        ),
+        (
+            FormsFallback::PrivateUse('\u{E000}'),
+            "مڼه ډېره ښه ده",
+            "\u{FEE3}\u{E032}\u{FEEA} \u{E010}\u{FBE6}\u{FEAE}\u{FEE9} \u{E025}\u{FEEA} \u{FEA9}\u{FEE9}",
+        ),
+    ];
+
+    for (forms_fallback, before, after) in cases {
+        let reshaper = reshaper(Language::Pashto, forms_fallback);
+        assert_eq!(reshaper.reshape(before), after);
+        assert_eq!(reshaper.unshape(after), before);
+    }
+}
+
+#[test]
+fn sindhi_reshaping() {
+    let cases = [
+        (
+            FormsFallback::Base,
+            "ڊاڪٽر",
+            "\u{068A}\u{FE8D}\u{06AA}\u{067D}\u{FEAE}",
+        ),
+        // two letters without forms next to each other share the ZWJ
+        (
+            FormsFallback::Zwj,
+            "ڊاڪٽر",
+            "\u{068A}\u{FE8D}\u{06AA}\u{200D}\u{067D}\u{200D}\u{FEAE}",
+        ),
+        (
+            FormsFallback::PrivateUse('\u{F000}'),
+            "ڊاڪٽر",
+            "\u{F014}\u{FE8D}\u{F029}\u{F006}\u{FEAE}",
+        ),
+    ];
+
+    for (forms_fallback, before, after) in cases {
+        let reshaper = reshaper(Language::Sindhi, forms_fallback);
+        assert_eq!(reshaper.reshape(before), after);
+        assert_eq!(reshaper.unshape(after), before);
+    }
+}
+
+#[test]
+fn tatweel_has_no_fallback() {
+    let cases = [
+        (Language::Arabic, "بـب", "\u{FE91}\u{0640}\u{FE90}"),
+        (Language::Pashto, "ښـه", "\u{069A}\u{200D}\u{0640}\u{FEEA}"),
+        (
+            Language::Sindhi,
+            "ڪـڪ",
+            "\u{06AA}\u{200D}\u{0640}\u{200D}\u{06AA}",
+        ),
+    ];
+
+    for (language, before, after) in cases {
+        let config = config(language, FormsFallback::Zwj);
+        let reshaper = ArabicReshaper::new(config.clone());
+        assert_eq!(reshaper.reshape(before), after);
+        assert_eq!(reshaper.unshape(after), before);
+
+        let reshaped: String = before.chars().reshape_chars_with_config(config).collect();
+        assert_eq!(reshaped, after);
+    }
+}
@@ -0,0 +1,86 @@
+use ar_reshaper::{
+    form::{Forms, JoiningType},
+    letters::{
+        letters_db::*,
+        unicode_db::{JOINING_TYPES, UNICODE_FORMS},
+    },
+};
+
+/// U WITH HAMZA ABOVE and NOON GHUNNA are joined in the Arabic and Kurdish tables
+/// like the first versions of the crate did, so the reshaped text doesn't change.
+const OLD_JOINING: [char; 2] = ['\u{0677}', '\u{06BA}'];
+
+fn unicode_forms(letter: char) -> Forms {
+    UNICODE_FORMS
+        .iter()
+        .find(|(c, _)| *c == letter)
+        .map_or(Forms::new('\0', '\0', '\0', '\0'), |(_, forms)| *forms)
+}
+
+fn joining_type(letter: char) -> JoiningType {
+    JOINING_TYPES
+        .iter()
+        .find(|(c, _)| *c == letter)
+        .map_or(JoiningType::NonJoining, |(_, joining_type)| *joining_type)
+}
+
+/// Check that the letters join like Unicode says and every presentation form is the right one,
+/// forms that are the letter itself are the ones without presentation forms and
+/// forms in the Private Use Area are font specific.
+fn check_letters(letters: &[(char, Forms)], skip: &[char]) {
+    for (letter, forms) in letters {
+        if [TATWEEL, ZWJ].contains(letter) || skip.contains(letter) {
+            continue;
+        }
+
+        assert_eq!(
+            forms.joining_type(),
+            joining_type(*letter),
+            "joining type of U+{:04X}",
+            *letter as u32
+        );
+
+        let unicode = unicode_forms(*letter);
+        for (form, expected) in [
+            (forms.isolated, unicode.isolated),
+            (forms.initial, unicode.initial),
+            (forms.medial, unicode.medial),
+            (forms.end, unicode.end),
+        ] {
+            if form != *letter && form != '\0' && !('\u{E000}'..='\u{F8FF}').contains(&form) {
+                assert_eq!(form, expected, "forms of U+{:04X}", *letter as u32);
+            }
+        }
+    }
+}
+
+#[test]
+fn arabic_letters_match_unicode() {
+    check_letters(&LETTERS_ARABIC, &OLD_JOINING);
+    check_letters(&LETTERS_ARABIC_V2, &OLD_JOINING);
+    // HEH is always written as the final HEH DOACHASHMEE in Kurdish
+    check_letters(
+        &LETTERS_KURDISH,
+        &[OLD_JOINING[0], OLD_JOINING[1], '\u{0647}'],
+    );
+}
+
+#[test]
+fn languages_letters_match_unicode() {
+    // written as HEH GOAL, KEHEH and FARSI YEH and HEH DOACHASHMEE that never use its isolated form
+    check_letters(
+        &LETTERS_URDU,
+        &['\u{0643}', '\u{0647}', '\u{064A}', '\u{06BE}'],
+    );
+    // the letters of LETTERS_ARABIC are used for Pashto and Sindhi too
+    check_letters(&LETTERS_PASHTO, &OLD_JOINING);
+    check_letters(&LETTERS_SINDHI, &OLD_JOINING);
+}
+
+#[test]
+fn letters_without_forms() {
+    for letter in LETTERS_WITHOUT_FORMS {
+        assert_eq!(unicode_forms(letter), Forms::new('\0', '\0', '\0', '\0'));
+        assert_ne!(joining_type(letter), JoiningType::NonJoining);
+    }
+}
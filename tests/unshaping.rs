@@ -0,0 +1,75 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
+
+#[test]
+fn unshape_round_trip() {
+    let cases = [
+        "سلام، حالت چطوره؟",
+        "السلام عليكم",
+        "اللغة العربية هي أكثر اللغات",
+        "في 18 ديسمبر كذكرى اعتماد",
+        "الأمم المتحدة.",
+        "Mixed متن and English",
+        "",
+    ];
+
+    for language in [Language::Arabic, Language::ArabicV2, Language::Kurdish] {
+        let reshaper =
+            ArabicReshaper::new(ReshaperConfig::new(language, LigaturesFlags::default()));
+
+        for text in cases {
+            assert_eq!(reshaper.unshape(reshaper.reshape(text)), text);
+        }
+    }
+}
+
+#[test]
+fn unshape_ligatures() {
+    let reshaper = ArabicReshaper::default();
+
+    let cases = [
+        ("\u{FDF2}", "الله"),
+        ("\u{FEFB}", "لا"),
+        ("ﺍﻟﺴﻼﻡ", "السلام"),
+        ("\u{FDFA}", "صلى الله عليه وسلم"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.unshape(before), after);
+    }
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn unshape_visual_ordering() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    for text in ["سلام دنیا", "من rust دوست دارم", "سال 2023", "(سلام)"] {
+        assert_eq!(reshaper.unshape(reshaper.reshape(text)), text);
+    }
+}
+
+#[cfg(feature = "bidi")]
+#[test]
+fn unshape_visual_ordering_mixed_direction() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    let cases = [
+        "سلام hello",
+        "سلام hello world",
+        "من rust 1.83 دارم",
+        "hello سلام world",
+        "Only English",
+        "یک\nدو hello\r\nسه hello world",
+    ];
+    for text in cases {
+        assert_eq!(reshaper.unshape(reshaper.reshape(text)), text);
+    }
+}
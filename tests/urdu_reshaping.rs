@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use ar_reshaper::{config::LigaturesFlags, ArabicReshaper, Language, ReshaperConfig};
+
+const RESHAPER: ArabicReshaper = ArabicReshaper::new(ReshaperConfig::new(
+    Language::Urdu,
+    LigaturesFlags::default(),
+));
+
+#[test]
+fn urdu_reshaping() {
+    let cases = [
+        ("میں اردو بولتا ہوں", "ﻣﯿﮟ ﺍﺭﺩﻭ ﺑﻮﻟﺘﺎ ﮨﻮﮞ"),
+        ("وہ کتاب پڑھ رہا ہے", "ﻭﮦ ﮐﺘﺎﺏ ﭘﮍﮬ ﺭﮨﺎ ﮨﮯ"),
+        ("دھوپ میں مت بیٹھو", "ﺩﮬﻮﭖ ﻣﯿﮟ ﻣﺖ ﺑﯿﭩﮭﻮ"),
+        ("کبھی کبھی مجھے", "ﮐﺒﮭﯽ ﮐﺒﮭﯽ ﻣﺠﮭﮯ"),
+        ("ڈھول", "ﮈﮬﻮﻝ"),
+        ("گھر", "ﮔﮭﺮ"),
+        ("لاہور", "ﻻﮨﻮﺭ"),
+        ("ورژن 1.5", "ﻭﺭﮊﻥ 1.5"),
+        ("", ""), // test empty input
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(RESHAPER.reshape(before), after);
+    }
+}
+
+#[test]
+fn urdu_normalization() {
+    let cases = [
+        // HEH, KAF and YEH are written as HEH GOAL, KEHEH and FARSI YEH
+        ("یه كتاب ميری ہے", "ﯾﮧ ﮐﺘﺎﺏ ﻣﯿﺮﯼ ﮨﮯ"),
+        // full stop after words and Urdu digits
+        ("پاکستان ایک خوبصورت ملک ہے.", "ﭘﺎﮐﺴﺘﺎﻥ ﺍﯾﮏ ﺧﻮﺑﺼﻮﺭﺕ ﻣﻠﮏ ﮨﮯ۔"),
+        ("قیمت ١٢٣ روپے ہے۔", "ﻗﯿﻤﺖ ۱۲۳ ﺭﻭﭘﮯ ﮨﮯ۔"),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(RESHAPER.reshape(before), after);
+    }
+}
+
+#[test]
+fn urdu_ligatures() {
+    let cases = [("اللہ", "ﷲ"), ("محمد صلی اللہ علیہ وسلم", "ﻣﺤﻤﺪ ﷺ")];
+
+    for (before, after) in cases {
+        assert_eq!(RESHAPER.reshape(before), after);
+    }
+
+    // the Urdu ligatures are not enabled for Arabic, and the default flags
+    // don't enable any ligature in the other languages
+    let config = ReshaperConfig::new(Language::Arabic, LigaturesFlags::default());
+    assert!(!config.support_ligatures);
+    let reshaper = ArabicReshaper::new(config);
+    assert_ne!(reshaper.reshape("صلی اللہ علیہ وسلم"), "ﷺ");
+    assert_eq!(reshaper.reshape("لا"), "ﻟﺎ");
+}
+
+#[test]
+fn urdu_unshape_round_trip() {
+    let cases = [
+        "امی",
+        "میں اردو بولتا ہوں",
+        "وہ کتاب پڑھ رہا ہے",
+        "کبھی کبھی مجھے",
+        "لاہور",
+    ];
+
+    for text in cases {
+        assert_eq!(RESHAPER.unshape(RESHAPER.reshape(text)), text);
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "bidi")]
+
+use ar_reshaper::{bidi::visual_order, ArabicReshaper, ReshaperConfig};
+
+#[test]
+fn visual_ordering() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    let cases = [
+        ("سلام دنیا", "ﺎﯿﻧﺩ ﻡﻼﺳ"),
+        ("من rust دوست دارم", "ﻡﺭﺍﺩ ﺖﺳﻭﺩ rust ﻦﻣ"),
+        ("سال 2023", "2023 ﻝﺎﺳ"),
+        ("hello سلام", "hello ﻡﻼﺳ"),
+        ("(سلام)", "(ﻡﻼﺳ)"),
+        ("Only English", "Only English"),
+        ("", ""),
+    ];
+
+    for (before, after) in cases {
+        assert_eq!(reshaper.reshape(before), after);
+    }
+}
+
+#[test]
+fn visual_ordering_with_harakat() {
+    let reshaper = ArabicReshaper::new(ReshaperConfig {
+        delete_harakat: false,
+        support_ligatures: false,
+        visual_ordering: true,
+        ..Default::default()
+    });
+
+    assert_eq!(reshaper.reshape("فُعِلَ"), "ﻞَﻌِﻓُ");
+}
+
+#[test]
+fn visual_ordering_keeps_line_breaks() {
+    assert_eq!(visual_order("یک\nدو\r\n"), "کی\nود\r\n");
+}
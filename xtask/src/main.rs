@@ -0,0 +1,223 @@
+//! Development tasks of the `ar-reshaper` crate, run them with `cargo xtask <task>`.
+//!
+//! Tasks:
+//! - `tables [--check]`: generate `src/letters/unicode_db.rs` from the Unicode data in `data/`,
+//!   with `--check` the file is only compared with the generated one.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+};
+
+const OUTPUT: &str = "src/letters/unicode_db.rs";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["tables"] => tables(false),
+        ["tables", "--check"] => tables(true),
+        _ => Err(String::from("usage: cargo xtask tables [--check]")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is inside the crate")
+        .to_path_buf()
+}
+
+fn tables(check: bool) -> Result<(), String> {
+    let root = root();
+    let read = |name: &str| {
+        fs::read_to_string(root.join("data").join(name)).map_err(|e| format!("{name}: {e}"))
+    };
+
+    let shaping = parse_shaping(&read("ArabicShaping.txt")?)?;
+    let forms = parse_forms(&read("UnicodeData.txt")?)?;
+    let code = format(&generate(&shaping, &forms))?;
+
+    let path = root.join(OUTPUT);
+    if check {
+        let current = fs::read_to_string(&path).map_err(|e| format!("{OUTPUT}: {e}"))?;
+        if current != code {
+            return Err(format!("{OUTPUT} is out of date, run `cargo xtask tables`"));
+        }
+    } else {
+        fs::write(&path, code).map_err(|e| format!("{OUTPUT}: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// A line of `ArabicShaping.txt`
+struct Shaping {
+    name: String,
+    joining_type: &'static str,
+}
+
+fn parse_code(code: &str) -> Result<char, String> {
+    u32::from_str_radix(code.trim(), 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("invalid code point `{code}`"))
+}
+
+fn parse_shaping(text: &str) -> Result<BTreeMap<char, Shaping>, String> {
+    let mut result = BTreeMap::new();
+
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        let [code, name, joining_type, _group] = fields[..] else {
+            return Err(format!("invalid line in ArabicShaping.txt: `{line}`"));
+        };
+
+        let joining_type = match joining_type {
+            "U" => "NonJoining",
+            "R" => "RightJoining",
+            "L" => "LeftJoining",
+            "D" => "DualJoining",
+            "C" => "JoinCausing",
+            "T" => "Transparent",
+            _ => return Err(format!("invalid joining type `{joining_type}`")),
+        };
+
+        result.insert(
+            parse_code(code)?,
+            Shaping {
+                name: name.to_string(),
+                joining_type,
+            },
+        );
+    }
+
+    Ok(result)
+}
+
+/// The isolated, initial, medial and final presentation forms of each letter
+fn parse_forms(text: &str) -> Result<BTreeMap<char, [Option<char>; 4]>, String> {
+    let mut result: BTreeMap<char, [Option<char>; 4]> = BTreeMap::new();
+
+    for line in text.lines() {
+        let fields: Vec<&str> = line.split(';').collect();
+        let (Some(code), Some(decomposition)) = (fields.first(), fields.get(5)) else {
+            continue;
+        };
+
+        let mut parts = decomposition.split_whitespace();
+        let form = match parts.next() {
+            Some("<isolated>") => 0,
+            Some("<initial>") => 1,
+            Some("<medial>") => 2,
+            Some("<final>") => 3,
+            _ => continue,
+        };
+
+        // ligatures are decomposed to more then one letter
+        let (Some(letter), None) = (parts.next(), parts.next()) else {
+            continue;
+        };
+
+        let forms = result.entry(parse_code(letter)?).or_default();
+        // some letters have more then one presentation form, the first one is used
+        forms[form].get_or_insert(parse_code(code)?);
+    }
+
+    Ok(result)
+}
+
+fn generate(
+    shaping: &BTreeMap<char, Shaping>,
+    forms: &BTreeMap<char, [Option<char>; 4]>,
+) -> String {
+    let mut code = String::from(
+        "//! Joining types and presentation forms of the letters, from the Unicode Character Database.
+//!
+//! Generated by `cargo xtask tables` from the files in `data/`, don't edit by hand.
+
+use crate::form::{Forms, JoiningType, LettersType};
+
+",
+    );
+
+    let name = |c: &char| match shaping.get(c) {
+        Some(shaping) => shaping.name.clone(),
+        None => format!("U+{:04X}", *c as u32),
+    };
+
+    code += "/// Joining type of the letters in the Arabic blocks\n";
+    code += &format!(
+        "pub const JOINING_TYPES: [(char, JoiningType); {}] = [\n",
+        shaping.len()
+    );
+    for (c, shaping) in shaping {
+        code += &format!("// {}\n", shaping.name);
+        code += &format!(
+            "('\\u{{{:04X}}}', JoiningType::{}),\n",
+            *c as u32, shaping.joining_type
+        );
+    }
+    code += "];\n\n";
+
+    code += "/// Presentation forms of the letters that have them\n";
+    code += &format!(
+        "pub const UNICODE_FORMS: [LettersType; {}] = [\n",
+        forms.len()
+    );
+    for (c, forms) in forms {
+        let [isolated, initial, medial, end] = forms.map(|form| match form {
+            Some(form) => format!("'\\u{{{:04X}}}'", form as u32),
+            None => String::from("'\\0'"),
+        });
+        code += &format!("// {}\n", name(c));
+        code += &format!(
+            "('\\u{{{:04X}}}', Forms::new({isolated}, {initial}, {medial}, {end})),\n",
+            *c as u32
+        );
+    }
+    code += "];\n";
+
+    code
+}
+
+/// Format the code with rustfmt
+fn format(code: &str) -> Result<String, String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("can't run rustfmt: {e}"))?;
+
+    rustfmt
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(code.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let output = rustfmt.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}